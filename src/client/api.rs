@@ -0,0 +1,309 @@
+//! # API
+//!
+//! Module containing the traits implemented by the clients of the Todoist REST API.
+
+use error::TodoistError;
+use model::{TaskId, ProjectId, LabelId, CommentId, SectionId, ReminderId};
+use model::task::Task;
+use model::project::Project;
+use model::label::Label;
+use model::comment::Comment;
+use model::section::Section;
+use model::reminder::Reminder;
+use model::user::Collaborator;
+use client::query::{TaskQuery, CommentFilter};
+use client::page::Page;
+#[cfg(feature = "async")]
+use futures_util::future::BoxFuture;
+
+/// Operations of the Todoist REST API.
+///
+/// Implemented by `TodoistClient`, and by `MockTodoistClient` when the `mock` feature is enabled,
+/// so that code using the API can accept `&dyn TodoistApi` or `impl TodoistApi` and be tested
+/// without network access. The methods fail with the same errors as those of `TodoistClient`.
+///
+/// # Example
+///
+/// ```
+/// use todoist_rest::client::api::TodoistApi;
+/// use todoist_rest::error::TodoistError;
+///
+/// fn count_labels(api: &dyn TodoistApi) -> Result<usize, TodoistError> {
+///     Ok(api.get_all_labels()?.len())
+/// }
+/// ```
+pub trait TodoistApi {
+    /// Gets a page of the active tasks, optionally narrowed down by a query.
+    fn get_all_tasks(&self, query: Option<&TaskQuery>, cursor: Option<&str>)
+            -> Result<Page<Task>, TodoistError>;
+
+    /// Gets the active tasks of all pages, optionally narrowed down by a query.
+    ///
+    /// The last page is the one without a cursor, with an empty cursor or with the cursor it was
+    /// requested with, so that a server repeating a cursor does not make this loop forever.
+    fn fetch_all_tasks(&self, query: Option<&TaskQuery>) -> Result<Vec<Task>, TodoistError> {
+        let mut tasks = vec![];
+        let mut cursor = None;
+
+        loop {
+            let page = self.get_all_tasks(query, cursor.as_deref())?;
+            let next_cursor = following_cursor(cursor.as_deref(), page.next_cursor());
+            tasks.extend(page.into_items());
+
+            match next_cursor {
+                Some(next_cursor) => cursor = Some(next_cursor),
+                None => return Ok(tasks)
+            }
+        }
+    }
+
+    /// Gets the task with the given identifier.
+    fn get_task(&self, id: TaskId) -> Result<Task, TodoistError>;
+
+    /// Creates a new task and returns it as stored by the server.
+    fn create_task(&self, task: &Task) -> Result<Task, TodoistError>;
+
+    /// Adds a task from a natural language text, as in "Buy milk tomorrow #Groceries p2".
+    fn quick_add(&self, text: &str) -> Result<Task, TodoistError>;
+
+    /// Updates a task that was fetched or created before, sending only its modified fields.
+    fn update_task(&self, task: &Task) -> Result<(), TodoistError>;
+
+    /// Deletes the task with the given identifier.
+    fn delete_task(&self, id: TaskId) -> Result<(), TodoistError>;
+
+    /// Closes (completes) the task with the given identifier.
+    fn close_task(&self, id: TaskId) -> Result<(), TodoistError>;
+
+    /// Reopens (uncompletes) the task with the given identifier.
+    fn reopen_task(&self, id: TaskId) -> Result<(), TodoistError>;
+
+    /// Moves the task with the given identifier to another project.
+    fn move_task(&self, id: TaskId, project_id: ProjectId) -> Result<(), TodoistError>;
+
+    /// Moves the task with the given identifier to a section.
+    fn move_task_to_section(&self, id: TaskId, section_id: SectionId) -> Result<(), TodoistError>;
+
+    /// Gets a page of all projects.
+    fn get_all_projects(&self, cursor: Option<&str>) -> Result<Page<Project>, TodoistError>;
+
+    /// Gets the project with the given identifier.
+    fn get_project(&self, id: ProjectId) -> Result<Project, TodoistError>;
+
+    /// Creates a new project and returns it as stored by the server.
+    fn create_project(&self, project: &Project) -> Result<Project, TodoistError>;
+
+    /// Updates the project with the given identifier.
+    fn update_project(&self, id: ProjectId, project: &Project) -> Result<(), TodoistError>;
+
+    /// Deletes the project with the given identifier, along with its tasks.
+    fn delete_project(&self, id: ProjectId) -> Result<(), TodoistError>;
+
+    /// Gets the users collaborating on a shared project.
+    fn get_all_collaborators(&self, project_id: ProjectId)
+            -> Result<Vec<Collaborator>, TodoistError>;
+
+    /// Gets all labels.
+    fn get_all_labels(&self) -> Result<Vec<Label>, TodoistError>;
+
+    /// Gets the label with the given identifier.
+    fn get_label(&self, id: LabelId) -> Result<Label, TodoistError>;
+
+    /// Creates a new label and returns it as stored by the server.
+    fn create_label(&self, label: &Label) -> Result<Label, TodoistError>;
+
+    /// Updates the label with the given identifier.
+    fn update_label(&self, id: LabelId, label: &Label) -> Result<(), TodoistError>;
+
+    /// Deletes the label with the given identifier.
+    fn delete_label(&self, id: LabelId) -> Result<(), TodoistError>;
+
+    /// Gets all comments of a task or a project.
+    fn get_comments(&self, filter: CommentFilter) -> Result<Vec<Comment>, TodoistError>;
+
+    /// Gets all comments of a task.
+    fn get_task_comments(&self, task_id: TaskId) -> Result<Vec<Comment>, TodoistError> {
+        self.get_comments(CommentFilter::for_task(task_id))
+    }
+
+    /// Gets all comments of a project.
+    fn get_project_comments(&self, project_id: ProjectId) -> Result<Vec<Comment>, TodoistError> {
+        self.get_comments(CommentFilter::for_project(project_id))
+    }
+
+    /// Gets the comment with the given identifier.
+    fn get_comment(&self, id: CommentId) -> Result<Comment, TodoistError>;
+
+    /// Posts a new comment on its task or project and returns it as stored by the server.
+    fn create_comment(&self, comment: &Comment) -> Result<Comment, TodoistError>;
+
+    /// Updates the content of the comment with the given identifier.
+    fn update_comment(&self, id: CommentId, content: &str) -> Result<(), TodoistError>;
+
+    /// Deletes the comment with the given identifier.
+    fn delete_comment(&self, id: CommentId) -> Result<(), TodoistError>;
+
+    /// Gets all sections, or only the sections of the given project.
+    fn get_sections(&self, project_id: Option<ProjectId>) -> Result<Vec<Section>, TodoistError>;
+
+    /// Gets the section with the given identifier.
+    fn get_section(&self, id: SectionId) -> Result<Section, TodoistError>;
+
+    /// Creates a new section and returns it as stored by the server.
+    fn create_section(&self, section: &Section) -> Result<Section, TodoistError>;
+
+    /// Renames the section with the given identifier.
+    fn update_section(&self, id: SectionId, name: &str) -> Result<(), TodoistError>;
+
+    /// Deletes the section with the given identifier, along with its tasks.
+    fn delete_section(&self, id: SectionId) -> Result<(), TodoistError>;
+
+    /// Gets all reminders of a task.
+    fn get_all_reminders(&self, task_id: TaskId) -> Result<Vec<Reminder>, TodoistError>;
+
+    /// Creates a new reminder and returns it as stored by the server.
+    fn create_reminder(&self, reminder: &Reminder) -> Result<Reminder, TodoistError>;
+
+    /// Updates the reminder with the given identifier.
+    fn update_reminder(&self, id: ReminderId, reminder: &Reminder) -> Result<(), TodoistError>;
+
+    /// Deletes the reminder with the given identifier.
+    fn delete_reminder(&self, id: ReminderId) -> Result<(), TodoistError>;
+}
+
+/// Gets the cursor of the page following the page requested with the given cursor, or `None` if
+/// the page is the last one because its cursor is missing, empty or the same as the requested
+/// one.
+pub(crate) fn following_cursor(cursor: Option<&str>, next_cursor: &Option<String>)
+        -> Option<String> {
+    next_cursor.as_ref()
+        .filter(|next_cursor| !next_cursor.is_empty() && Some(next_cursor.as_str()) != cursor)
+        .cloned()
+}
+
+/// Boxed future resolving to the result of an `AsyncTodoistApi` operation.
+#[cfg(feature = "async")]
+pub type ApiFuture<T> = BoxFuture<'static, Result<T, TodoistError>>;
+
+/// Operations of the Todoist REST API returning futures, enabled by the `async` feature.
+///
+/// Implemented by `TodoistAsyncClient`. The futures are boxed so that the trait can be used as
+/// `&dyn AsyncTodoistApi`, and fail with the same errors as those of `TodoistAsyncClient`.
+#[cfg(feature = "async")]
+pub trait AsyncTodoistApi {
+    /// Gets a page of the active tasks, optionally narrowed down by a query.
+    fn get_all_tasks(&self, query: Option<&TaskQuery>, cursor: Option<&str>)
+            -> ApiFuture<Page<Task>>;
+
+    /// Gets the task with the given identifier.
+    fn get_task(&self, id: TaskId) -> ApiFuture<Task>;
+
+    /// Creates a new task and resolves to it as stored by the server.
+    fn create_task(&self, task: &Task) -> ApiFuture<Task>;
+
+    /// Adds a task from a natural language text, as in "Buy milk tomorrow #Groceries p2".
+    fn quick_add(&self, text: &str) -> ApiFuture<Task>;
+
+    /// Updates a task that was fetched or created before, sending only its modified fields.
+    fn update_task(&self, task: &Task) -> ApiFuture<()>;
+
+    /// Deletes the task with the given identifier.
+    fn delete_task(&self, id: TaskId) -> ApiFuture<()>;
+
+    /// Closes (completes) the task with the given identifier.
+    fn close_task(&self, id: TaskId) -> ApiFuture<()>;
+
+    /// Reopens (uncompletes) the task with the given identifier.
+    fn reopen_task(&self, id: TaskId) -> ApiFuture<()>;
+
+    /// Moves the task with the given identifier to another project.
+    fn move_task(&self, id: TaskId, project_id: ProjectId) -> ApiFuture<()>;
+
+    /// Moves the task with the given identifier to a section.
+    fn move_task_to_section(&self, id: TaskId, section_id: SectionId) -> ApiFuture<()>;
+
+    /// Gets a page of all projects.
+    fn get_all_projects(&self, cursor: Option<&str>) -> ApiFuture<Page<Project>>;
+
+    /// Gets the project with the given identifier.
+    fn get_project(&self, id: ProjectId) -> ApiFuture<Project>;
+
+    /// Creates a new project and resolves to it as stored by the server.
+    fn create_project(&self, project: &Project) -> ApiFuture<Project>;
+
+    /// Updates the project with the given identifier.
+    fn update_project(&self, id: ProjectId, project: &Project) -> ApiFuture<()>;
+
+    /// Deletes the project with the given identifier, along with its tasks.
+    fn delete_project(&self, id: ProjectId) -> ApiFuture<()>;
+
+    /// Gets the users collaborating on a shared project.
+    fn get_all_collaborators(&self, project_id: ProjectId) -> ApiFuture<Vec<Collaborator>>;
+
+    /// Gets all labels.
+    fn get_all_labels(&self) -> ApiFuture<Vec<Label>>;
+
+    /// Gets the label with the given identifier.
+    fn get_label(&self, id: LabelId) -> ApiFuture<Label>;
+
+    /// Creates a new label and resolves to it as stored by the server.
+    fn create_label(&self, label: &Label) -> ApiFuture<Label>;
+
+    /// Updates the label with the given identifier.
+    fn update_label(&self, id: LabelId, label: &Label) -> ApiFuture<()>;
+
+    /// Deletes the label with the given identifier.
+    fn delete_label(&self, id: LabelId) -> ApiFuture<()>;
+
+    /// Gets all comments of a task or a project.
+    fn get_comments(&self, filter: CommentFilter) -> ApiFuture<Vec<Comment>>;
+
+    /// Gets all comments of a task.
+    fn get_task_comments(&self, task_id: TaskId) -> ApiFuture<Vec<Comment>> {
+        self.get_comments(CommentFilter::for_task(task_id))
+    }
+
+    /// Gets all comments of a project.
+    fn get_project_comments(&self, project_id: ProjectId) -> ApiFuture<Vec<Comment>> {
+        self.get_comments(CommentFilter::for_project(project_id))
+    }
+
+    /// Gets the comment with the given identifier.
+    fn get_comment(&self, id: CommentId) -> ApiFuture<Comment>;
+
+    /// Posts a new comment on its task or project and resolves to it as stored by the server.
+    fn create_comment(&self, comment: &Comment) -> ApiFuture<Comment>;
+
+    /// Updates the content of the comment with the given identifier.
+    fn update_comment(&self, id: CommentId, content: &str) -> ApiFuture<()>;
+
+    /// Deletes the comment with the given identifier.
+    fn delete_comment(&self, id: CommentId) -> ApiFuture<()>;
+
+    /// Gets all sections, or only the sections of the given project.
+    fn get_sections(&self, project_id: Option<ProjectId>) -> ApiFuture<Vec<Section>>;
+
+    /// Gets the section with the given identifier.
+    fn get_section(&self, id: SectionId) -> ApiFuture<Section>;
+
+    /// Creates a new section and resolves to it as stored by the server.
+    fn create_section(&self, section: &Section) -> ApiFuture<Section>;
+
+    /// Renames the section with the given identifier.
+    fn update_section(&self, id: SectionId, name: &str) -> ApiFuture<()>;
+
+    /// Deletes the section with the given identifier, along with its tasks.
+    fn delete_section(&self, id: SectionId) -> ApiFuture<()>;
+
+    /// Gets all reminders of a task.
+    fn get_all_reminders(&self, task_id: TaskId) -> ApiFuture<Vec<Reminder>>;
+
+    /// Creates a new reminder and resolves to it as stored by the server.
+    fn create_reminder(&self, reminder: &Reminder) -> ApiFuture<Reminder>;
+
+    /// Updates the reminder with the given identifier.
+    fn update_reminder(&self, id: ReminderId, reminder: &Reminder) -> ApiFuture<()>;
+
+    /// Deletes the reminder with the given identifier.
+    fn delete_reminder(&self, id: ReminderId) -> ApiFuture<()>;
+}
@@ -0,0 +1,848 @@
+//! # Async Client
+//!
+//! Module containing a non-blocking client for the Todoist REST API, enabled by the `async`
+//! feature.
+//!
+//! The methods of `TodoistAsyncClient` mirror those of the blocking `TodoistClient`, but return
+//! futures that must be driven by a Tokio runtime.
+
+use std::env;
+use std::future::Future;
+use futures_util::future::{self, Either, FutureExt, TryFutureExt};
+use reqwest::{Client, Method, RequestBuilder, Response, StatusCode};
+use serde::de::DeserializeOwned;
+use serde_json;
+use error::TodoistError;
+use model::{TaskId, ProjectId, LabelId, CommentId, SectionId, ReminderId};
+use model::task::Task;
+use model::project::Project;
+use model::label::Label;
+use model::comment::Comment;
+use model::section::Section;
+use model::reminder::Reminder;
+use model::user::Collaborator;
+use super::{BASE_URL, SYNC_BASE_URL, NEXT_CURSOR_HEADER, TOKEN_VAR, CommentUpdate, QuickAdd, TaskMove,
+            SectionUpdate};
+use super::{retry_after, missing_task_id};
+use super::query::{TaskQuery, CommentFilter};
+use super::page::Page;
+use super::api::{AsyncTodoistApi, ApiFuture};
+
+/// Client sending non-blocking requests to the Todoist REST API on behalf of a user.
+///
+/// Rate limited requests are never retried; they fail with `TodoistError::RateLimited` so the
+/// caller can wait using the timer of its own runtime.
+pub struct TodoistAsyncClient {
+    /// API token of the user
+    token: String,
+    /// Base URL the endpoint paths are appended to
+    base_url: String,
+    /// Base URL the Sync API endpoint paths are appended to
+    sync_base_url: String,
+    /// Underlying HTTP client
+    http: Client
+}
+
+impl TodoistAsyncClient {
+    /// Creates a new client authenticating with the given API token.
+    ///
+    /// The token can be found in the Todoist settings under "Integrations".
+    pub fn new(token: &str) -> TodoistAsyncClient {
+        TodoistAsyncClient::with_base_urls(token, BASE_URL, SYNC_BASE_URL)
+    }
+
+    /// Creates a new client authenticating with the API token in the `TODOIST_API_TOKEN`
+    /// environment variable.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TodoistError::MissingToken` if the variable is not set, is empty or is not valid
+    /// unicode.
+    pub fn from_env() -> Result<TodoistAsyncClient, TodoistError> {
+        match env::var(TOKEN_VAR) {
+            Ok(ref token) if !token.trim().is_empty() => Ok(TodoistAsyncClient::new(token.trim())),
+            _ => Err(TodoistError::MissingToken)
+        }
+    }
+
+    /// Creates a new client sending its requests to the given base URLs of the REST and Sync
+    /// APIs.
+    fn with_base_urls(token: &str, base_url: &str, sync_base_url: &str) -> TodoistAsyncClient {
+        TodoistAsyncClient {
+            token: String::from(token),
+            base_url: String::from(base_url.trim_end_matches('/')),
+            sync_base_url: String::from(sync_base_url.trim_end_matches('/')),
+            http: Client::new()
+        }
+    }
+
+    /// Creates a new client sending its requests to the given base URL.
+    #[cfg(test)]
+    fn with_base_url(token: &str, base_url: &str) -> TodoistAsyncClient {
+        TodoistAsyncClient::with_base_urls(token, base_url, SYNC_BASE_URL)
+    }
+
+    /// Gets a page of the active tasks, optionally narrowed down by a query.
+    ///
+    /// Pass `None` as the cursor to get the first page, and the `next_cursor` of a page to get
+    /// the page following it.
+    ///
+    /// # Errors
+    ///
+    /// Resolves to a `TodoistError` if the request fails or the API responds with an error.
+    pub fn get_all_tasks(&self, query: Option<&TaskQuery>, cursor: Option<&str>)
+            -> impl Future<Output = Result<Page<Task>, TodoistError>> {
+        let mut request = self.request(Method::GET, "/tasks");
+
+        if let Some(query) = query {
+            request = request.query(&query.query_pairs());
+        }
+
+        send_page(request, cursor)
+    }
+
+    /// Gets the task with the given identifier.
+    ///
+    /// # Errors
+    ///
+    /// Resolves to `TodoistError::NotFound` if there is no such task, or another `TodoistError`
+    /// if the request fails or the API responds with an error.
+    pub fn get_task(&self, id: TaskId) -> impl Future<Output = Result<Task, TodoistError>> {
+        send(self.request(Method::GET, &format!("/tasks/{}", id)))
+    }
+
+    /// Creates a new task and resolves to it as stored by the server.
+    ///
+    /// # Errors
+    ///
+    /// Resolves to a `TodoistError` if the request fails or the API responds with an error.
+    pub fn create_task(&self, task: &Task) -> impl Future<Output = Result<Task, TodoistError>> {
+        send(self.request(Method::POST, "/tasks").json(task))
+    }
+
+    /// Adds a task the way the quick add of the Todoist apps does and resolves to it as stored by
+    /// the server.
+    ///
+    /// The REST API has no quick add endpoint, so the request is sent to the `/quick/add`
+    /// endpoint of the Sync API.
+    ///
+    /// # Errors
+    ///
+    /// Resolves to a `TodoistError` if the request fails or the API responds with an error.
+    pub fn quick_add(&self, text: &str) -> impl Future<Output = Result<Task, TodoistError>> {
+        send(self.sync_request(Method::POST, "/quick/add").json(&QuickAdd { text }))
+    }
+
+    /// Updates a task that was fetched or created before, using its identifier.
+    ///
+    /// Only the fields modified since the task was fetched or its changes were last reset are
+    /// sent, as returned by `Task::update_payload`.
+    ///
+    /// # Errors
+    ///
+    /// Resolves to `TodoistError::InvalidArgument` if the task has no identifier,
+    /// `TodoistError::NotFound` if there is no such task, or another `TodoistError` if the
+    /// request fails or the API responds with an error.
+    pub fn update_task(&self, task: &Task) -> impl Future<Output = Result<(), TodoistError>> {
+        match *task.id() {
+            Some(id) => {
+                let request = self.request(Method::POST, &format!("/tasks/{}", id));
+                Either::Left(send_empty(request.json(&task.update_payload())))
+            },
+            None => Either::Right(future::err(missing_task_id()))
+        }
+    }
+
+    /// Deletes the task with the given identifier.
+    ///
+    /// # Errors
+    ///
+    /// Resolves to `TodoistError::NotFound` if there is no such task, or another `TodoistError`
+    /// if the request fails or the API responds with an error.
+    pub fn delete_task(&self, id: TaskId) -> impl Future<Output = Result<(), TodoistError>> {
+        send_empty(self.request(Method::DELETE, &format!("/tasks/{}", id)))
+    }
+
+    /// Closes (completes) the task with the given identifier.
+    ///
+    /// # Errors
+    ///
+    /// Resolves to `TodoistError::NotFound` if there is no such task, or another `TodoistError`
+    /// if the request fails or the API responds with an error.
+    pub fn close_task(&self, id: TaskId) -> impl Future<Output = Result<(), TodoistError>> {
+        send_empty(self.request(Method::POST, &format!("/tasks/{}/close", id)))
+    }
+
+    /// Reopens (uncompletes) the task with the given identifier.
+    ///
+    /// # Errors
+    ///
+    /// Resolves to `TodoistError::NotFound` if there is no such task, or another `TodoistError`
+    /// if the request fails or the API responds with an error.
+    pub fn reopen_task(&self, id: TaskId) -> impl Future<Output = Result<(), TodoistError>> {
+        send_empty(self.request(Method::POST, &format!("/tasks/{}/reopen", id)))
+    }
+
+    /// Moves the task with the given identifier to another project.
+    ///
+    /// The server does not send the moved task back; use `get_task` to fetch it.
+    ///
+    /// # Errors
+    ///
+    /// Resolves to `TodoistError::NotFound` if there is no such task, or another `TodoistError`
+    /// if the request fails or the API responds with an error.
+    pub fn move_task(&self, id: TaskId, project_id: ProjectId)
+            -> impl Future<Output = Result<(), TodoistError>> {
+        let body = TaskMove { project_id: Some(project_id), section_id: None };
+        send_empty(self.request(Method::POST, &format!("/tasks/{}", id)).json(&body))
+    }
+
+    /// Moves the task with the given identifier to a section.
+    ///
+    /// The server does not send the moved task back; use `get_task` to fetch it.
+    ///
+    /// # Errors
+    ///
+    /// Resolves to `TodoistError::NotFound` if there is no such task, or another `TodoistError`
+    /// if the request fails or the API responds with an error.
+    pub fn move_task_to_section(&self, id: TaskId, section_id: SectionId)
+            -> impl Future<Output = Result<(), TodoistError>> {
+        let body = TaskMove { project_id: None, section_id: Some(section_id) };
+        send_empty(self.request(Method::POST, &format!("/tasks/{}", id)).json(&body))
+    }
+
+    /// Gets a page of all projects.
+    ///
+    /// # Errors
+    ///
+    /// Resolves to a `TodoistError` if the request fails or the API responds with an error.
+    pub fn get_all_projects(&self, cursor: Option<&str>)
+            -> impl Future<Output = Result<Page<Project>, TodoistError>> {
+        send_page(self.request(Method::GET, "/projects"), cursor)
+    }
+
+    /// Gets the project with the given identifier.
+    ///
+    /// # Errors
+    ///
+    /// Resolves to `TodoistError::NotFound` if there is no such project, or another
+    /// `TodoistError` if the request fails or the API responds with an error.
+    pub fn get_project(&self, id: ProjectId)
+            -> impl Future<Output = Result<Project, TodoistError>> {
+        send(self.request(Method::GET, &format!("/projects/{}", id)))
+    }
+
+    /// Creates a new project and resolves to it as stored by the server.
+    ///
+    /// # Errors
+    ///
+    /// Resolves to a `TodoistError` if the request fails or the API responds with an error.
+    pub fn create_project(&self, project: &Project)
+            -> impl Future<Output = Result<Project, TodoistError>> {
+        send(self.request(Method::POST, "/projects").json(project))
+    }
+
+    /// Updates the project with the given identifier, sending the body given by
+    /// `Project::to_update_json`.
+    ///
+    /// # Errors
+    ///
+    /// Resolves to `TodoistError::NotFound` if there is no such project, or another
+    /// `TodoistError` if the request fails or the API responds with an error.
+    pub fn update_project(&self, id: ProjectId, project: &Project)
+            -> impl Future<Output = Result<(), TodoistError>> {
+        let request = self.request(Method::POST, &format!("/projects/{}", id));
+        send_empty(request.json(&project.update_body()))
+    }
+
+    /// Deletes the project with the given identifier, along with its tasks.
+    ///
+    /// # Errors
+    ///
+    /// Resolves to `TodoistError::NotFound` if there is no such project, or another
+    /// `TodoistError` if the request fails or the API responds with an error.
+    pub fn delete_project(&self, id: ProjectId) -> impl Future<Output = Result<(), TodoistError>> {
+        send_empty(self.request(Method::DELETE, &format!("/projects/{}", id)))
+    }
+
+    /// Gets the users collaborating on a shared project.
+    ///
+    /// # Errors
+    ///
+    /// Resolves to `TodoistError::NotFound` if there is no such project, or another
+    /// `TodoistError` if the request fails or the API responds with an error.
+    pub fn get_all_collaborators(&self, project_id: ProjectId)
+            -> impl Future<Output = Result<Vec<Collaborator>, TodoistError>> {
+        send(self.request(Method::GET, &format!("/projects/{}/collaborators", project_id)))
+    }
+
+    /// Gets all labels.
+    ///
+    /// # Errors
+    ///
+    /// Resolves to a `TodoistError` if the request fails or the API responds with an error.
+    pub fn get_all_labels(&self) -> impl Future<Output = Result<Vec<Label>, TodoistError>> {
+        send(self.request(Method::GET, "/labels"))
+    }
+
+    /// Gets the label with the given identifier.
+    ///
+    /// # Errors
+    ///
+    /// Resolves to `TodoistError::NotFound` if there is no such label, or another `TodoistError`
+    /// if the request fails or the API responds with an error.
+    pub fn get_label(&self, id: LabelId) -> impl Future<Output = Result<Label, TodoistError>> {
+        send(self.request(Method::GET, &format!("/labels/{}", id)))
+    }
+
+    /// Creates a new label and resolves to it as stored by the server.
+    ///
+    /// # Errors
+    ///
+    /// Resolves to a `TodoistError` if the request fails or the API responds with an error.
+    pub fn create_label(&self, label: &Label) -> impl Future<Output = Result<Label, TodoistError>> {
+        send(self.request(Method::POST, "/labels").json(label))
+    }
+
+    /// Updates the label with the given identifier.
+    ///
+    /// # Errors
+    ///
+    /// Resolves to `TodoistError::NotFound` if there is no such label, or another `TodoistError`
+    /// if the request fails or the API responds with an error.
+    pub fn update_label(&self, id: LabelId, label: &Label)
+            -> impl Future<Output = Result<(), TodoistError>> {
+        send_empty(self.request(Method::POST, &format!("/labels/{}", id)).json(label))
+    }
+
+    /// Deletes the label with the given identifier.
+    ///
+    /// # Errors
+    ///
+    /// Resolves to `TodoistError::NotFound` if there is no such label, or another `TodoistError`
+    /// if the request fails or the API responds with an error.
+    pub fn delete_label(&self, id: LabelId) -> impl Future<Output = Result<(), TodoistError>> {
+        send_empty(self.request(Method::DELETE, &format!("/labels/{}", id)))
+    }
+
+    /// Gets all comments of a task or a project.
+    ///
+    /// # Errors
+    ///
+    /// Resolves to a `TodoistError` if the request fails or the API responds with an error.
+    pub fn get_comments(&self, filter: CommentFilter)
+            -> impl Future<Output = Result<Vec<Comment>, TodoistError>> {
+        send(self.request(Method::GET, "/comments").query(&filter.query_pairs()))
+    }
+
+    /// Gets all comments of a task.
+    ///
+    /// # Errors
+    ///
+    /// Resolves to a `TodoistError` if the request fails or the API responds with an error.
+    pub fn get_task_comments(&self, task_id: TaskId)
+            -> impl Future<Output = Result<Vec<Comment>, TodoistError>> {
+        self.get_comments(CommentFilter::for_task(task_id))
+    }
+
+    /// Gets all comments of a project.
+    ///
+    /// # Errors
+    ///
+    /// Resolves to a `TodoistError` if the request fails or the API responds with an error.
+    pub fn get_project_comments(&self, project_id: ProjectId)
+            -> impl Future<Output = Result<Vec<Comment>, TodoistError>> {
+        self.get_comments(CommentFilter::for_project(project_id))
+    }
+
+    /// Gets the comment with the given identifier.
+    ///
+    /// # Errors
+    ///
+    /// Resolves to `TodoistError::NotFound` if there is no such comment, or another
+    /// `TodoistError` if the request fails or the API responds with an error.
+    pub fn get_comment(&self, id: CommentId) -> impl Future<Output = Result<Comment, TodoistError>> {
+        send(self.request(Method::GET, &format!("/comments/{}", id)))
+    }
+
+    /// Posts a new comment on its task or project and resolves to it as stored by the server.
+    ///
+    /// # Errors
+    ///
+    /// Resolves to a `TodoistError` if the request fails or the API responds with an error.
+    pub fn create_comment(&self, comment: &Comment)
+            -> impl Future<Output = Result<Comment, TodoistError>> {
+        send(self.request(Method::POST, "/comments").json(comment))
+    }
+
+    /// Updates the content of the comment with the given identifier.
+    ///
+    /// # Errors
+    ///
+    /// Resolves to `TodoistError::NotFound` if there is no such comment, or another
+    /// `TodoistError` if the request fails or the API responds with an error.
+    pub fn update_comment(&self, id: CommentId, content: &str)
+            -> impl Future<Output = Result<(), TodoistError>> {
+        let body = CommentUpdate { content };
+        send_empty(self.request(Method::POST, &format!("/comments/{}", id)).json(&body))
+    }
+
+    /// Deletes the comment with the given identifier.
+    ///
+    /// # Errors
+    ///
+    /// Resolves to `TodoistError::NotFound` if there is no such comment, or another
+    /// `TodoistError` if the request fails or the API responds with an error.
+    pub fn delete_comment(&self, id: CommentId) -> impl Future<Output = Result<(), TodoistError>> {
+        send_empty(self.request(Method::DELETE, &format!("/comments/{}", id)))
+    }
+
+    /// Gets all sections, or only the sections of the given project.
+    ///
+    /// # Errors
+    ///
+    /// Resolves to a `TodoistError` if the request fails or the API responds with an error.
+    pub fn get_sections(&self, project_id: Option<ProjectId>)
+            -> impl Future<Output = Result<Vec<Section>, TodoistError>> {
+        let mut request = self.request(Method::GET, "/sections");
+
+        if let Some(project_id) = project_id {
+            request = request.query(&[("project_id", project_id)]);
+        }
+
+        send(request)
+    }
+
+    /// Gets the section with the given identifier.
+    ///
+    /// # Errors
+    ///
+    /// Resolves to `TodoistError::NotFound` if there is no such section, or another
+    /// `TodoistError` if the request fails or the API responds with an error.
+    pub fn get_section(&self, id: SectionId) -> impl Future<Output = Result<Section, TodoistError>> {
+        send(self.request(Method::GET, &format!("/sections/{}", id)))
+    }
+
+    /// Creates a new section and resolves to it as stored by the server.
+    ///
+    /// # Errors
+    ///
+    /// Resolves to a `TodoistError` if the request fails or the API responds with an error.
+    pub fn create_section(&self, section: &Section)
+            -> impl Future<Output = Result<Section, TodoistError>> {
+        send(self.request(Method::POST, "/sections").json(section))
+    }
+
+    /// Renames the section with the given identifier.
+    ///
+    /// # Errors
+    ///
+    /// Resolves to `TodoistError::NotFound` if there is no such section, or another
+    /// `TodoistError` if the request fails or the API responds with an error.
+    pub fn update_section(&self, id: SectionId, name: &str)
+            -> impl Future<Output = Result<(), TodoistError>> {
+        let body = SectionUpdate { name };
+        send_empty(self.request(Method::POST, &format!("/sections/{}", id)).json(&body))
+    }
+
+    /// Deletes the section with the given identifier, along with its tasks.
+    ///
+    /// # Errors
+    ///
+    /// Resolves to `TodoistError::NotFound` if there is no such section, or another
+    /// `TodoistError` if the request fails or the API responds with an error.
+    pub fn delete_section(&self, id: SectionId) -> impl Future<Output = Result<(), TodoistError>> {
+        send_empty(self.request(Method::DELETE, &format!("/sections/{}", id)))
+    }
+
+    /// Gets all reminders of a task.
+    ///
+    /// # Errors
+    ///
+    /// Resolves to a `TodoistError` if the request fails or the API responds with an error.
+    pub fn get_all_reminders(&self, task_id: TaskId)
+            -> impl Future<Output = Result<Vec<Reminder>, TodoistError>> {
+        send(self.request(Method::GET, "/reminders").query(&[("task_id", task_id)]))
+    }
+
+    /// Creates a new reminder and resolves to it as stored by the server.
+    ///
+    /// # Errors
+    ///
+    /// Resolves to a `TodoistError` if the request fails or the API responds with an error.
+    pub fn create_reminder(&self, reminder: &Reminder)
+            -> impl Future<Output = Result<Reminder, TodoistError>> {
+        send(self.request(Method::POST, "/reminders").json(reminder))
+    }
+
+    /// Updates the reminder with the given identifier.
+    ///
+    /// # Errors
+    ///
+    /// Resolves to `TodoistError::NotFound` if there is no such reminder, or another
+    /// `TodoistError` if the request fails or the API responds with an error.
+    pub fn update_reminder(&self, id: ReminderId, reminder: &Reminder)
+            -> impl Future<Output = Result<(), TodoistError>> {
+        send_empty(self.request(Method::POST, &format!("/reminders/{}", id)).json(reminder))
+    }
+
+    /// Deletes the reminder with the given identifier.
+    ///
+    /// # Errors
+    ///
+    /// Resolves to `TodoistError::NotFound` if there is no such reminder, or another
+    /// `TodoistError` if the request fails or the API responds with an error.
+    pub fn delete_reminder(&self, id: ReminderId)
+            -> impl Future<Output = Result<(), TodoistError>> {
+        send_empty(self.request(Method::DELETE, &format!("/reminders/{}", id)))
+    }
+
+    /// Starts an authenticated request to the given endpoint path.
+    fn request(&self, method: Method, path: &str) -> RequestBuilder {
+        self.http.request(method, format!("{}{}", self.base_url, path))
+            .bearer_auth(&self.token)
+    }
+
+    /// Starts an authenticated request to the given endpoint path of the Sync API.
+    fn sync_request(&self, method: Method, path: &str) -> RequestBuilder {
+        self.http.request(method, format!("{}{}", self.sync_base_url, path))
+            .bearer_auth(&self.token)
+    }
+}
+
+/// Delegates to the inherent methods of the client, which take precedence in method calls.
+impl AsyncTodoistApi for TodoistAsyncClient {
+    fn get_all_tasks(&self, query: Option<&TaskQuery>, cursor: Option<&str>)
+            -> ApiFuture<Page<Task>> {
+        TodoistAsyncClient::get_all_tasks(self, query, cursor).boxed()
+    }
+
+    fn get_task(&self, id: TaskId) -> ApiFuture<Task> {
+        TodoistAsyncClient::get_task(self, id).boxed()
+    }
+
+    fn create_task(&self, task: &Task) -> ApiFuture<Task> {
+        TodoistAsyncClient::create_task(self, task).boxed()
+    }
+
+    fn quick_add(&self, text: &str) -> ApiFuture<Task> {
+        TodoistAsyncClient::quick_add(self, text).boxed()
+    }
+
+    fn update_task(&self, task: &Task) -> ApiFuture<()> {
+        TodoistAsyncClient::update_task(self, task).boxed()
+    }
+
+    fn delete_task(&self, id: TaskId) -> ApiFuture<()> {
+        TodoistAsyncClient::delete_task(self, id).boxed()
+    }
+
+    fn close_task(&self, id: TaskId) -> ApiFuture<()> {
+        TodoistAsyncClient::close_task(self, id).boxed()
+    }
+
+    fn reopen_task(&self, id: TaskId) -> ApiFuture<()> {
+        TodoistAsyncClient::reopen_task(self, id).boxed()
+    }
+
+    fn move_task(&self, id: TaskId, project_id: ProjectId) -> ApiFuture<()> {
+        TodoistAsyncClient::move_task(self, id, project_id).boxed()
+    }
+
+    fn move_task_to_section(&self, id: TaskId, section_id: SectionId) -> ApiFuture<()> {
+        TodoistAsyncClient::move_task_to_section(self, id, section_id).boxed()
+    }
+
+    fn get_all_projects(&self, cursor: Option<&str>) -> ApiFuture<Page<Project>> {
+        TodoistAsyncClient::get_all_projects(self, cursor).boxed()
+    }
+
+    fn get_project(&self, id: ProjectId) -> ApiFuture<Project> {
+        TodoistAsyncClient::get_project(self, id).boxed()
+    }
+
+    fn create_project(&self, project: &Project) -> ApiFuture<Project> {
+        TodoistAsyncClient::create_project(self, project).boxed()
+    }
+
+    fn update_project(&self, id: ProjectId, project: &Project) -> ApiFuture<()> {
+        TodoistAsyncClient::update_project(self, id, project).boxed()
+    }
+
+    fn delete_project(&self, id: ProjectId) -> ApiFuture<()> {
+        TodoistAsyncClient::delete_project(self, id).boxed()
+    }
+
+    fn get_all_collaborators(&self, project_id: ProjectId) -> ApiFuture<Vec<Collaborator>> {
+        TodoistAsyncClient::get_all_collaborators(self, project_id).boxed()
+    }
+
+    fn get_all_labels(&self) -> ApiFuture<Vec<Label>> {
+        TodoistAsyncClient::get_all_labels(self).boxed()
+    }
+
+    fn get_label(&self, id: LabelId) -> ApiFuture<Label> {
+        TodoistAsyncClient::get_label(self, id).boxed()
+    }
+
+    fn create_label(&self, label: &Label) -> ApiFuture<Label> {
+        TodoistAsyncClient::create_label(self, label).boxed()
+    }
+
+    fn update_label(&self, id: LabelId, label: &Label) -> ApiFuture<()> {
+        TodoistAsyncClient::update_label(self, id, label).boxed()
+    }
+
+    fn delete_label(&self, id: LabelId) -> ApiFuture<()> {
+        TodoistAsyncClient::delete_label(self, id).boxed()
+    }
+
+    fn get_comments(&self, filter: CommentFilter) -> ApiFuture<Vec<Comment>> {
+        TodoistAsyncClient::get_comments(self, filter).boxed()
+    }
+
+    fn get_comment(&self, id: CommentId) -> ApiFuture<Comment> {
+        TodoistAsyncClient::get_comment(self, id).boxed()
+    }
+
+    fn create_comment(&self, comment: &Comment) -> ApiFuture<Comment> {
+        TodoistAsyncClient::create_comment(self, comment).boxed()
+    }
+
+    fn update_comment(&self, id: CommentId, content: &str) -> ApiFuture<()> {
+        TodoistAsyncClient::update_comment(self, id, content).boxed()
+    }
+
+    fn delete_comment(&self, id: CommentId) -> ApiFuture<()> {
+        TodoistAsyncClient::delete_comment(self, id).boxed()
+    }
+
+    fn get_sections(&self, project_id: Option<ProjectId>) -> ApiFuture<Vec<Section>> {
+        TodoistAsyncClient::get_sections(self, project_id).boxed()
+    }
+
+    fn get_section(&self, id: SectionId) -> ApiFuture<Section> {
+        TodoistAsyncClient::get_section(self, id).boxed()
+    }
+
+    fn create_section(&self, section: &Section) -> ApiFuture<Section> {
+        TodoistAsyncClient::create_section(self, section).boxed()
+    }
+
+    fn update_section(&self, id: SectionId, name: &str) -> ApiFuture<()> {
+        TodoistAsyncClient::update_section(self, id, name).boxed()
+    }
+
+    fn delete_section(&self, id: SectionId) -> ApiFuture<()> {
+        TodoistAsyncClient::delete_section(self, id).boxed()
+    }
+
+    fn get_all_reminders(&self, task_id: TaskId) -> ApiFuture<Vec<Reminder>> {
+        TodoistAsyncClient::get_all_reminders(self, task_id).boxed()
+    }
+
+    fn create_reminder(&self, reminder: &Reminder) -> ApiFuture<Reminder> {
+        TodoistAsyncClient::create_reminder(self, reminder).boxed()
+    }
+
+    fn update_reminder(&self, id: ReminderId, reminder: &Reminder) -> ApiFuture<()> {
+        TodoistAsyncClient::update_reminder(self, id, reminder).boxed()
+    }
+
+    fn delete_reminder(&self, id: ReminderId) -> ApiFuture<()> {
+        TodoistAsyncClient::delete_reminder(self, id).boxed()
+    }
+}
+
+/// Sends a request and deserializes the JSON body of a successful response.
+fn send<T: DeserializeOwned>(request: RequestBuilder)
+        -> impl Future<Output = Result<T, TodoistError>> {
+    execute(request)
+        .and_then(|response| response.text().map_err(TodoistError::from))
+        .and_then(|body| future::ready(serde_json::from_str(&body).map_err(TodoistError::from)))
+}
+
+/// Sends a request for the page at the given cursor and deserializes its items.
+fn send_page<T: DeserializeOwned>(mut request: RequestBuilder, cursor: Option<&str>)
+        -> impl Future<Output = Result<Page<T>, TodoistError>> {
+    if let Some(cursor) = cursor {
+        request = request.query(&[("cursor", cursor)]);
+    }
+
+    execute(request)
+        .and_then(|response| {
+            let next_cursor = response.headers().get(NEXT_CURSOR_HEADER)
+                .and_then(|value| value.to_str().ok())
+                .filter(|cursor| !cursor.is_empty())
+                .map(String::from);
+
+            response.text().map_err(TodoistError::from).map_ok(|body| (body, next_cursor))
+        })
+        .and_then(|(body, next_cursor)| {
+            future::ready(serde_json::from_str(&body)
+                .map(|items| Page::new(items, next_cursor))
+                .map_err(TodoistError::from))
+        })
+}
+
+/// Sends a request expecting a successful response without content (i.e. 204).
+fn send_empty(request: RequestBuilder) -> impl Future<Output = Result<(), TodoistError>> {
+    execute(request).map_ok(|_| ())
+}
+
+/// Sends a request and resolves to the successful response.
+fn execute(request: RequestBuilder) -> impl Future<Output = Result<Response, TodoistError>> {
+    request.send().map_err(TodoistError::from).and_then(check)
+}
+
+/// Turns an unsuccessful response into the matching error.
+fn check(response: Response) -> impl Future<Output = Result<Response, TodoistError>> {
+    let status = response.status();
+
+    if status.is_success() {
+        Either::Left(future::ok(response))
+    } else if status == StatusCode::NOT_FOUND {
+        Either::Left(future::err(TodoistError::NotFound))
+    } else if status == StatusCode::TOO_MANY_REQUESTS {
+        Either::Left(future::err(TodoistError::RateLimited {
+            retry_after: retry_after(response.headers())
+        }))
+    } else {
+        Either::Right(response.text().map(move |message| Err(TodoistError::Api {
+            status: status.as_u16(),
+            message: message?
+        })))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate mockito;
+    extern crate tokio;
+    use std::future::Future;
+    use self::mockito::Matcher;
+    use client::async_client::TodoistAsyncClient;
+    use client::api::AsyncTodoistApi;
+    use error::TodoistError;
+    use model::section::Section;
+
+    /// Runs a future to completion on a new single-threaded runtime.
+    fn block_on<F: Future>(future: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(future)
+    }
+
+    #[test]
+    fn get_all_labels() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("GET", "/labels")
+            .match_header("Authorization", "Bearer token")
+            .with_body(r#"[{"id": 124, "name": "Food", "color": 31, "order": 1, "favorite": false},
+                           {"id": 125, "name": "Shopping", "color": 32, "order": 2, "favorite": true}]"#)
+            .create();
+
+        let client = TodoistAsyncClient::with_base_url("token", &server.url());
+        let labels = block_on(client.get_all_labels()).unwrap();
+        mock.assert();
+        assert_eq!(labels.len(), 2);
+        assert_eq!(labels[1].name(), "Shopping");
+    }
+
+    #[test]
+    fn quick_add() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("POST", "/sync/v9/quick/add")
+            .match_header("Authorization", "Bearer token")
+            .match_body(Matcher::JsonString(String::from(r#"{"text": "Buy milk tomorrow"}"#)))
+            .with_body(r#"{"id": "1234", "content": "Buy milk", "project_id": "2345"}"#)
+            .create();
+
+        let client = TodoistAsyncClient::with_base_urls("token", &format!("{}/rest/v1", server.url()),
+                                                        &format!("{}/sync/v9", server.url()));
+        let task = block_on(client.quick_add("Buy milk tomorrow")).unwrap();
+        mock.assert();
+        assert_eq!(task.id(), &Some(1234));
+        assert_eq!(task.content(), "Buy milk");
+    }
+
+    #[test]
+    fn get_all_tasks_page() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("GET", "/tasks")
+            .match_query(Matcher::UrlEncoded(String::from("cursor"), String::from("abc")))
+            .with_header("X-Next-Cursor", "def")
+            .with_body(r#"[{"id": 123, "project_id": 456, "content": "My task", "priority": 1}]"#)
+            .create();
+
+        let client = TodoistAsyncClient::with_base_url("token", &server.url());
+        let page = block_on(client.get_all_tasks(None, Some("abc"))).unwrap();
+        mock.assert();
+        assert_eq!(page.items()[0].content(), "My task");
+        assert_eq!(page.next_cursor(), &Some(String::from("def")));
+    }
+
+    #[test]
+    fn create_section() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("POST", "/sections")
+            .match_body(Matcher::JsonString(String::from(r#"{"project_id": 2345, "name": "Groceries"}"#)))
+            .with_body(r#"{"id": 7025, "project_id": 2345, "order": 1, "name": "Groceries"}"#)
+            .create();
+
+        let client = TodoistAsyncClient::with_base_url("token", &server.url());
+        let section = block_on(client.create_section(&Section::create("Groceries", 2345))).unwrap();
+        mock.assert();
+        assert_eq!(section.id(), &Some(7025));
+    }
+
+    #[test]
+    fn errors() {
+        let mut server = mockito::Server::new();
+        let not_found = server.mock("DELETE", "/labels/124")
+            .with_status(404)
+            .create();
+        let limited = server.mock("POST", "/tasks/123/close")
+            .with_status(429)
+            .with_header("Retry-After", "30")
+            .create();
+        let forbidden = server.mock("GET", "/comments/2992")
+            .with_status(403)
+            .with_body("Forbidden")
+            .create();
+
+        let client = TodoistAsyncClient::with_base_url("token", &server.url());
+        assert!(matches!(block_on(client.delete_label(124)), Err(TodoistError::NotFound)));
+        assert!(matches!(block_on(client.close_task(123)),
+                         Err(TodoistError::RateLimited { retry_after: Some(30) })));
+
+        match block_on(client.get_comment(2992)) {
+            Err(TodoistError::Api { status, message }) => {
+                assert_eq!(status, 403);
+                assert_eq!(message, "Forbidden");
+            },
+            result => panic!("unexpected result: {:?}", result.map(|_| ()))
+        }
+
+        not_found.assert();
+        limited.assert();
+        forbidden.assert();
+    }
+
+    #[test]
+    fn get_task_comments_through_trait_object() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("GET", "/comments")
+            .match_query(Matcher::Exact(String::from("task_id=1234")))
+            .with_body(r#"[{"id": 3456, "task_id": 1234, "content": "Note"}]"#)
+            .create();
+
+        let client: Box<dyn AsyncTodoistApi> =
+            Box::new(TodoistAsyncClient::with_base_url("token", &server.url()));
+        let comments = block_on(client.get_task_comments(1234)).unwrap();
+        mock.assert();
+        assert_eq!(comments[0].content(), "Note");
+    }
+}
@@ -0,0 +1,67 @@
+//! # Response Metadata
+//!
+//! Module containing the metadata recorded for the last response received by a client.
+
+use std::time::Duration;
+
+/// Response header carrying the identifier the server assigned to the request.
+pub(crate) const REQUEST_ID_HEADER: &str = "X-Request-Id";
+
+/// Metadata of the last response received by a `TodoistClient`, such as the request identifier
+/// to quote when filing a support ticket with Todoist.
+///
+/// # Example
+///
+/// ```no_run
+/// use todoist_rest::client::TodoistClient;
+///
+/// let client = TodoistClient::new("0123456789abcdef");
+/// let result = client.get_all_labels();
+///
+/// if let Some(meta) = client.last_response_meta() {
+///     println!("{} after {} attempt(s), request id {:?}", meta.status(), meta.attempts(),
+///              meta.request_id());
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResponseMeta {
+    /// HTTP status code of the response
+    status: u16,
+    /// Identifier of the request echoed by the server in the `X-Request-Id` header, if any
+    request_id: Option<String>,
+    /// Number of attempts made, including retries of rate limited requests
+    attempts: u32,
+    /// Time from sending the first attempt to receiving the response, including waits before
+    /// retries
+    duration: Duration
+}
+
+impl ResponseMeta {
+    /// Creates the metadata of a response.
+    pub(crate) fn new(status: u16, request_id: Option<String>, attempts: u32, duration: Duration)
+            -> ResponseMeta {
+        ResponseMeta { status, request_id, attempts, duration }
+    }
+
+    /// Gets the HTTP status code of the response.
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    /// Gets the identifier of the request echoed by the server in the `X-Request-Id` header, if
+    /// any.
+    pub fn request_id(&self) -> &Option<String> {
+        &self.request_id
+    }
+
+    /// Gets the number of attempts made, including retries of rate limited requests.
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    /// Gets the time from sending the first attempt to receiving the response, including waits
+    /// before retries.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+}
@@ -0,0 +1,407 @@
+//! # Mock
+//!
+//! Module containing an in-memory stand-in for the Todoist REST API, enabled by the `mock`
+//! feature.
+//!
+//! `MockTodoistClient` implements `TodoistApi` without any network access, so that applications
+//! using this crate can be tested deterministically.
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use error::TodoistError;
+use model::{TaskId, ProjectId, LabelId, CommentId, SectionId, ReminderId};
+use model::task::Task;
+use model::project::Project;
+use model::label::Label;
+use model::comment::Comment;
+use model::section::Section;
+use model::reminder::Reminder;
+use model::user::Collaborator;
+use client::missing_task_id;
+use client::api::TodoistApi;
+use client::query::{TaskQuery, CommentFilter};
+use client::page::Page;
+
+/// In-memory implementation of `TodoistApi` holding tasks and projects.
+///
+/// Tasks and projects can be created, read, updated and deleted, and the changes are visible to
+/// the following calls. All tasks and projects are returned in a single page ordered by
+/// identifier. Filter queries are not supported.
+///
+/// The other resources are not stored: listing them gives no items, looking them up or changing
+/// them fails with `TodoistError::NotFound`, and creating them fails with
+/// `TodoistError::InvalidArgument`.
+///
+/// # Example
+///
+/// ```
+/// use todoist_rest::client::api::TodoistApi;
+/// use todoist_rest::client::mock::MockTodoistClient;
+/// use todoist_rest::model::task::Task;
+///
+/// let client = MockTodoistClient::new();
+/// let task = client.create_task(&Task::create("Buy milk")).unwrap();
+/// client.close_task(task.id().unwrap()).unwrap();
+/// assert!(client.fetch_all_tasks(None).unwrap().is_empty());
+/// assert!(client.task(task.id().unwrap()).unwrap().completed());
+/// ```
+#[derive(Debug, Default)]
+pub struct MockTodoistClient {
+    /// Stored tasks by identifier
+    tasks: RefCell<HashMap<TaskId, Task>>,
+    /// Stored projects by identifier
+    projects: RefCell<HashMap<ProjectId, Project>>,
+    /// Identifier given to the next created task or project
+    next_id: Cell<u64>
+}
+
+impl MockTodoistClient {
+    /// Creates a new client without any tasks or projects.
+    pub fn new() -> MockTodoistClient {
+        MockTodoistClient::default()
+    }
+
+    /// Stores a task as is and returns its identifier, which is newly assigned if it has none.
+    pub fn add_task(&self, mut task: Task) -> TaskId {
+        let id = task.id().unwrap_or_else(|| self.next_id());
+        task.set_id(id);
+        self.tasks.borrow_mut().insert(id, task);
+        id
+    }
+
+    /// Stores a project as is and returns its identifier, which is newly assigned if it has none.
+    pub fn add_project(&self, mut project: Project) -> ProjectId {
+        let id = project.id().unwrap_or_else(|| self.next_id());
+        project.set_id(id);
+        self.projects.borrow_mut().insert(id, project);
+        id
+    }
+
+    /// Gets the stored task with the given identifier, including completed tasks.
+    pub fn task(&self, id: TaskId) -> Option<Task> {
+        self.tasks.borrow().get(&id).cloned()
+    }
+
+    /// Gets the stored project with the given identifier.
+    pub fn project(&self, id: ProjectId) -> Option<Project> {
+        self.projects.borrow().get(&id).cloned()
+    }
+
+    /// Gets an identifier that is not used by any stored task or project.
+    fn next_id(&self) -> u64 {
+        let tasks = self.tasks.borrow();
+        let projects = self.projects.borrow();
+        let mut id = self.next_id.get();
+
+        loop {
+            id += 1;
+
+            if !tasks.contains_key(&id) && !projects.contains_key(&id) {
+                self.next_id.set(id);
+                return id;
+            }
+        }
+    }
+
+    /// Applies a change to the stored task with the given identifier and returns the changed task.
+    fn change_task<F>(&self, id: TaskId, change: F) -> Result<Task, TodoistError> where
+        F: FnOnce(&mut Task) {
+        match self.tasks.borrow_mut().get_mut(&id) {
+            Some(task) => {
+                change(task);
+                task.reset_changes();
+                Ok(task.clone())
+            },
+            None => Err(TodoistError::NotFound)
+        }
+    }
+}
+
+/// Gets the error returned when creating a resource that is not stored by the mock.
+fn unsupported<T>(resources: &str) -> Result<T, TodoistError> {
+    Err(TodoistError::InvalidArgument(format!("{} are not supported by MockTodoistClient",
+                                              resources)))
+}
+
+impl TodoistApi for MockTodoistClient {
+    fn get_all_tasks(&self, query: Option<&TaskQuery>, _cursor: Option<&str>)
+            -> Result<Page<Task>, TodoistError> {
+        if query.is_some_and(|query| query.filter().is_some()) {
+            return unsupported("filter queries");
+        }
+
+        let mut tasks: Vec<Task> = self.tasks.borrow().values()
+            .filter(|task| !task.completed())
+            .filter(|task| query.is_none_or(|query| {
+                query.project_id().is_none_or(|id| *task.project_id() == Some(id)) &&
+                    query.label_id().is_none_or(|id| task.has_label(id)) &&
+                    query.ids().as_ref().is_none_or(|ids| ids.contains(&task.id().unwrap()))
+            }))
+            .cloned()
+            .collect();
+        tasks.sort_by_key(|task| task.id().unwrap());
+
+        Ok(Page::new(tasks, None))
+    }
+
+    fn get_task(&self, id: TaskId) -> Result<Task, TodoistError> {
+        self.task(id).ok_or(TodoistError::NotFound)
+    }
+
+    fn create_task(&self, task: &Task) -> Result<Task, TodoistError> {
+        let mut task = task.clone();
+        task.set_id(self.next_id());
+        task.reset_changes();
+        self.add_task(task.clone());
+        Ok(task)
+    }
+
+    fn quick_add(&self, _text: &str) -> Result<Task, TodoistError> {
+        unsupported("quick add requests")
+    }
+
+    fn update_task(&self, task: &Task) -> Result<(), TodoistError> {
+        let id = task.id().ok_or_else(missing_task_id)?;
+
+        match self.tasks.borrow_mut().get_mut(&id) {
+            Some(stored) => {
+                *stored = task.clone();
+                stored.reset_changes();
+                Ok(())
+            },
+            None => Err(TodoistError::NotFound)
+        }
+    }
+
+    fn delete_task(&self, id: TaskId) -> Result<(), TodoistError> {
+        self.tasks.borrow_mut().remove(&id).map(|_| ()).ok_or(TodoistError::NotFound)
+    }
+
+    fn close_task(&self, id: TaskId) -> Result<(), TodoistError> {
+        self.change_task(id, |task| task.set_completed(true)).map(|_| ())
+    }
+
+    fn reopen_task(&self, id: TaskId) -> Result<(), TodoistError> {
+        self.change_task(id, |task| task.set_completed(false)).map(|_| ())
+    }
+
+    fn move_task(&self, id: TaskId, project_id: ProjectId) -> Result<(), TodoistError> {
+        self.change_task(id, |task| {
+            task.set_project_id(Some(project_id));
+            task.set_section_id(None);
+        }).map(|_| ())
+    }
+
+    fn move_task_to_section(&self, id: TaskId, section_id: SectionId)
+            -> Result<(), TodoistError> {
+        self.change_task(id, |task| task.set_section_id(Some(section_id))).map(|_| ())
+    }
+
+    fn get_all_projects(&self, _cursor: Option<&str>) -> Result<Page<Project>, TodoistError> {
+        let mut projects: Vec<Project> = self.projects.borrow().values().cloned().collect();
+        projects.sort_by_key(|project| project.id().unwrap());
+
+        Ok(Page::new(projects, None))
+    }
+
+    fn get_project(&self, id: ProjectId) -> Result<Project, TodoistError> {
+        self.project(id).ok_or(TodoistError::NotFound)
+    }
+
+    fn create_project(&self, project: &Project) -> Result<Project, TodoistError> {
+        let mut project = project.clone();
+        project.set_id(self.next_id());
+        self.add_project(project.clone());
+        Ok(project)
+    }
+
+    fn update_project(&self, id: ProjectId, project: &Project) -> Result<(), TodoistError> {
+        match self.projects.borrow_mut().get_mut(&id) {
+            Some(stored) => {
+                *stored = project.clone();
+                stored.set_id(id);
+                Ok(())
+            },
+            None => Err(TodoistError::NotFound)
+        }
+    }
+
+    fn delete_project(&self, id: ProjectId) -> Result<(), TodoistError> {
+        self.projects.borrow_mut().remove(&id).ok_or(TodoistError::NotFound)?;
+        self.tasks.borrow_mut().retain(|_, task| *task.project_id() != Some(id));
+        Ok(())
+    }
+
+    fn get_all_collaborators(&self, project_id: ProjectId)
+            -> Result<Vec<Collaborator>, TodoistError> {
+        self.get_project(project_id).map(|_| vec![])
+    }
+
+    fn get_all_labels(&self) -> Result<Vec<Label>, TodoistError> {
+        Ok(vec![])
+    }
+
+    fn get_label(&self, _id: LabelId) -> Result<Label, TodoistError> {
+        Err(TodoistError::NotFound)
+    }
+
+    fn create_label(&self, _label: &Label) -> Result<Label, TodoistError> {
+        unsupported("labels")
+    }
+
+    fn update_label(&self, _id: LabelId, _label: &Label) -> Result<(), TodoistError> {
+        Err(TodoistError::NotFound)
+    }
+
+    fn delete_label(&self, _id: LabelId) -> Result<(), TodoistError> {
+        Err(TodoistError::NotFound)
+    }
+
+    fn get_comments(&self, _filter: CommentFilter) -> Result<Vec<Comment>, TodoistError> {
+        Ok(vec![])
+    }
+
+    fn get_comment(&self, _id: CommentId) -> Result<Comment, TodoistError> {
+        Err(TodoistError::NotFound)
+    }
+
+    fn create_comment(&self, _comment: &Comment) -> Result<Comment, TodoistError> {
+        unsupported("comments")
+    }
+
+    fn update_comment(&self, _id: CommentId, _content: &str) -> Result<(), TodoistError> {
+        Err(TodoistError::NotFound)
+    }
+
+    fn delete_comment(&self, _id: CommentId) -> Result<(), TodoistError> {
+        Err(TodoistError::NotFound)
+    }
+
+    fn get_sections(&self, _project_id: Option<ProjectId>) -> Result<Vec<Section>, TodoistError> {
+        Ok(vec![])
+    }
+
+    fn get_section(&self, _id: SectionId) -> Result<Section, TodoistError> {
+        Err(TodoistError::NotFound)
+    }
+
+    fn create_section(&self, _section: &Section) -> Result<Section, TodoistError> {
+        unsupported("sections")
+    }
+
+    fn update_section(&self, _id: SectionId, _name: &str) -> Result<(), TodoistError> {
+        Err(TodoistError::NotFound)
+    }
+
+    fn delete_section(&self, _id: SectionId) -> Result<(), TodoistError> {
+        Err(TodoistError::NotFound)
+    }
+
+    fn get_all_reminders(&self, _task_id: TaskId) -> Result<Vec<Reminder>, TodoistError> {
+        Ok(vec![])
+    }
+
+    fn create_reminder(&self, _reminder: &Reminder) -> Result<Reminder, TodoistError> {
+        unsupported("reminders")
+    }
+
+    fn update_reminder(&self, _id: ReminderId, _reminder: &Reminder) -> Result<(), TodoistError> {
+        Err(TodoistError::NotFound)
+    }
+
+    fn delete_reminder(&self, _id: ReminderId) -> Result<(), TodoistError> {
+        Err(TodoistError::NotFound)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use client::api::TodoistApi;
+    use client::mock::MockTodoistClient;
+    use client::query::TaskQuery;
+    use error::TodoistError;
+    use model::task::Task;
+    use model::project::Project;
+    use model::label::Label;
+
+    #[test]
+    fn create_update_and_delete_tasks() {
+        let client = MockTodoistClient::new();
+        let project = client.create_project(&Project::create("Groceries")).unwrap();
+        let project_id = project.id().unwrap();
+
+        let mut milk = Task::create("Buy milk");
+        milk.add_label_id(4);
+        let milk = client.create_task(&milk).unwrap();
+        let bread = client.create_task(&Task::create("Buy bread")).unwrap();
+        let milk_id = milk.id().unwrap();
+        let bread_id = bread.id().unwrap();
+        assert_ne!(milk_id, project_id);
+        assert_ne!(milk_id, bread_id);
+
+        client.move_task(bread_id, project_id).unwrap();
+        assert_eq!(client.get_task(bread_id).unwrap().project_id(), &Some(project_id));
+
+        let mut query = TaskQuery::new();
+        query.set_project_id(Some(project_id));
+        let tasks = client.fetch_all_tasks(Some(&query)).unwrap();
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].content(), "Buy bread");
+
+        let mut query = TaskQuery::new();
+        query.set_label_id(Some(4));
+        assert_eq!(client.fetch_all_tasks(Some(&query)).unwrap()[0].id(), &Some(milk_id));
+
+        client.close_task(milk_id).unwrap();
+        assert_eq!(client.fetch_all_tasks(None).unwrap().len(), 1);
+        client.reopen_task(milk_id).unwrap();
+        assert_eq!(client.fetch_all_tasks(None).unwrap().len(), 2);
+
+        let mut milk = client.get_task(milk_id).unwrap();
+        milk.set_content("Buy oat milk");
+        client.update_task(&milk).unwrap();
+        assert_eq!(client.task(milk_id).unwrap().content(), "Buy oat milk");
+        assert!(client.update_task(&Task::create("Buy eggs")).is_err());
+
+        client.delete_task(milk_id).unwrap();
+        match client.get_task(milk_id) {
+            Err(TodoistError::NotFound) => {},
+            result => panic!("unexpected result {:?}", result)
+        }
+
+        client.delete_project(project_id).unwrap();
+        assert!(client.task(bread_id).is_none());
+        assert!(client.get_all_projects(None).unwrap().items().is_empty());
+    }
+
+    #[test]
+    fn update_projects() {
+        let client = MockTodoistClient::new();
+        let id = client.add_project(Project::create("Groceries"));
+
+        let mut project = client.get_project(id).unwrap();
+        project.set_name("Shopping");
+        client.update_project(id, &project).unwrap();
+        assert_eq!(client.project(id).unwrap().name(), "Shopping");
+
+        assert!(client.update_project(id + 1, &project).is_err());
+        assert!(client.delete_project(id + 1).is_err());
+    }
+
+    #[test]
+    fn use_unsupported_resources() {
+        let client: Box<dyn TodoistApi> = Box::new(MockTodoistClient::new());
+        assert!(client.get_all_labels().unwrap().is_empty());
+
+        match client.create_label(&Label::create("Food")) {
+            Err(TodoistError::InvalidArgument(message)) => {
+                assert_eq!(message, "labels are not supported by MockTodoistClient")
+            },
+            result => panic!("unexpected result {:?}", result)
+        }
+
+        let mut query = TaskQuery::new();
+        query.set_filter(Some("today"));
+        assert!(client.get_all_tasks(Some(&query), None).is_err());
+    }
+}
@@ -0,0 +1,1968 @@
+//! # Client
+//!
+//! Module containing the client used to send requests to the Todoist REST API.
+
+pub mod query;
+pub mod page;
+pub mod api;
+pub mod meta;
+#[cfg(feature = "mock")]
+pub mod mock;
+#[cfg(feature = "async")]
+pub mod async_client;
+
+use std::env;
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+use reqwest::{Method, StatusCode};
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::blocking::{Client, RequestBuilder, Response};
+use serde::de::DeserializeOwned;
+use serde_json;
+use error::TodoistError;
+use model::{TaskId, ProjectId, LabelId, CommentId, SectionId, ReminderId};
+use model::id;
+use model::task::Task;
+use model::project::Project;
+use model::label::Label;
+use model::comment::Comment;
+use model::section::Section;
+use model::reminder::Reminder;
+use model::user::Collaborator;
+use self::query::{TaskQuery, CommentFilter};
+use self::page::{Page, TaskPages};
+use self::api::TodoistApi;
+use self::meta::{ResponseMeta, REQUEST_ID_HEADER};
+
+/// Base URL of the Todoist REST API.
+const BASE_URL: &str = "https://api.todoist.com/rest/v1";
+
+/// Base URL of the Todoist Sync API, which serves the quick add endpoint missing from the REST API.
+const SYNC_BASE_URL: &str = "https://api.todoist.com/sync/v9";
+
+/// Environment variable holding the API token read by `TodoistClient::from_env`.
+pub const TOKEN_VAR: &str = "TODOIST_API_TOKEN";
+
+/// Response header carrying the cursor of the following page.
+const NEXT_CURSOR_HEADER: &str = "X-Next-Cursor";
+
+/// Maximum number of seconds to wait before retrying a rate limited request, used by
+/// `RetryPolicy::fixed` and `RetryPolicy::exponential`.
+pub const DEFAULT_MAX_RETRY_DELAY_SECS: u64 = 60;
+
+/// Policy deciding whether and when requests hitting the API rate limit are retried.
+///
+/// When the API responds with 429 (Too Many Requests), the client waits for the number of seconds
+/// given in the `Retry-After` header, or for the delay of the policy if there is no such header,
+/// and sends the request again. The number of attempts includes the first request. The wait never
+/// exceeds the maximum delay of the policy, so a server asking for a long wait cannot stall a
+/// call; `RetryPolicy::fixed` and `RetryPolicy::exponential` use a maximum of 60 seconds.
+///
+/// Only GET requests are retried. Other requests change data, and are not sent again in case the
+/// change was applied despite the rate limit response.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetryPolicy {
+    /// Never retry rate limited requests
+    NoRetry,
+    /// Retry rate limited requests after the same delay each time
+    RetryFixed {
+        /// Maximum number of attempts, including the first request
+        max_attempts: u32,
+        /// Number of seconds to wait before each retry
+        delay_secs: u64,
+        /// Maximum number of seconds to wait before a retry, even if the server asks for longer
+        max_delay_secs: u64
+    },
+    /// Retry rate limited requests after a delay doubling with each retry
+    RetryExponential {
+        /// Maximum number of attempts, including the first request
+        max_attempts: u32,
+        /// Number of seconds to wait before the first retry
+        base_delay_secs: u64,
+        /// Maximum number of seconds to wait before a retry, even if the server asks for longer
+        max_delay_secs: u64
+    }
+}
+
+impl RetryPolicy {
+    /// Creates a policy retrying after the same delay each time, waiting at most
+    /// `DEFAULT_MAX_RETRY_DELAY_SECS` seconds when the server asks for longer.
+    pub fn fixed(max_attempts: u32, delay_secs: u64) -> RetryPolicy {
+        RetryPolicy::RetryFixed {
+            max_attempts,
+            delay_secs,
+            max_delay_secs: DEFAULT_MAX_RETRY_DELAY_SECS
+        }
+    }
+
+    /// Creates a policy retrying after a delay doubling with each retry, waiting at most
+    /// `DEFAULT_MAX_RETRY_DELAY_SECS` seconds before a retry.
+    pub fn exponential(max_attempts: u32, base_delay_secs: u64) -> RetryPolicy {
+        RetryPolicy::RetryExponential {
+            max_attempts,
+            base_delay_secs,
+            max_delay_secs: DEFAULT_MAX_RETRY_DELAY_SECS
+        }
+    }
+
+    /// Gets the delay before the next attempt after the given number of attempts, or `None` if
+    /// no further attempts are allowed.
+    ///
+    /// The delay asked for by the server is used if there is one, and is capped at the maximum
+    /// delay of the policy like the policy's own delay.
+    fn delay(&self, attempts: u32, retry_after: Option<u64>) -> Option<Duration> {
+        let (max_attempts, delay_secs, max_delay_secs) = match *self {
+            RetryPolicy::NoRetry => return None,
+            RetryPolicy::RetryFixed { max_attempts, delay_secs, max_delay_secs } => {
+                (max_attempts, delay_secs, max_delay_secs)
+            },
+            RetryPolicy::RetryExponential { max_attempts, base_delay_secs, max_delay_secs } => {
+                let factor = 2u64.saturating_pow(attempts.saturating_sub(1));
+                (max_attempts, base_delay_secs.saturating_mul(factor), max_delay_secs)
+            }
+        };
+
+        if attempts < max_attempts {
+            Some(Duration::from_secs(retry_after.unwrap_or(delay_secs).min(max_delay_secs)))
+        } else {
+            None
+        }
+    }
+}
+
+/// Settings of a `TodoistClient`.
+///
+/// The default configuration sends requests to the Todoist REST API without a timeout, and does
+/// not retry rate limited requests.
+///
+/// # Example
+///
+/// ```
+/// use todoist_rest::client::{TodoistClient, TodoistClientConfig, RetryPolicy};
+///
+/// let mut config = TodoistClientConfig::new();
+/// config.set_timeout_secs(Some(30));
+/// config.set_retry_policy(RetryPolicy::fixed(3, 5));
+/// let client = TodoistClient::with_config("0123456789abcdef", config);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TodoistClientConfig {
+    /// Base URL the endpoint paths are appended to
+    base_url: String,
+    /// Base URL the Sync API endpoint paths are appended to
+    sync_base_url: String,
+    /// Number of seconds after which a request fails, if any
+    timeout_secs: Option<u64>,
+    /// Policy for retrying rate limited requests
+    retry_policy: RetryPolicy
+}
+
+impl TodoistClientConfig {
+    /// Creates the default configuration.
+    pub fn new() -> TodoistClientConfig {
+        TodoistClientConfig::default()
+    }
+
+    /// Sets the base URL the endpoint paths are appended to, such as that of a proxy or a local
+    /// server used for testing.
+    pub fn set_base_url(&mut self, base_url: &str) {
+        self.base_url = String::from(base_url.trim_end_matches('/'));
+    }
+
+    /// Sets the base URL the Sync API endpoint paths are appended to, used by
+    /// `TodoistClient::quick_add`.
+    pub fn set_sync_base_url(&mut self, sync_base_url: &str) {
+        self.sync_base_url = String::from(sync_base_url.trim_end_matches('/'));
+    }
+
+    /// Sets the number of seconds after which a request fails with a timeout, or `None` to wait
+    /// indefinitely.
+    pub fn set_timeout_secs(&mut self, timeout_secs: Option<u64>) {
+        self.timeout_secs = timeout_secs;
+    }
+
+    /// Sets the policy for retrying rate limited requests.
+    pub fn set_retry_policy(&mut self, retry_policy: RetryPolicy) {
+        self.retry_policy = retry_policy;
+    }
+
+    /// Gets the base URL the endpoint paths are appended to.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Gets the base URL the Sync API endpoint paths are appended to.
+    pub fn sync_base_url(&self) -> &str {
+        &self.sync_base_url
+    }
+
+    /// Gets the number of seconds after which a request fails with a timeout, if any.
+    pub fn timeout_secs(&self) -> &Option<u64> {
+        &self.timeout_secs
+    }
+
+    /// Gets the policy for retrying rate limited requests.
+    pub fn retry_policy(&self) -> RetryPolicy {
+        self.retry_policy
+    }
+}
+
+impl Default for TodoistClientConfig {
+    fn default() -> TodoistClientConfig {
+        TodoistClientConfig {
+            base_url: String::from(BASE_URL),
+            sync_base_url: String::from(SYNC_BASE_URL),
+            timeout_secs: None,
+            retry_policy: RetryPolicy::NoRetry
+        }
+    }
+}
+
+/// Request body updating the content of a comment.
+#[derive(Serialize)]
+struct CommentUpdate<'a> {
+    content: &'a str
+}
+
+/// Request body adding a task from a natural language text.
+#[derive(Serialize)]
+struct QuickAdd<'a> {
+    text: &'a str
+}
+
+/// Request body moving a task to another project or section.
+#[derive(Serialize)]
+struct TaskMove {
+    #[serde(skip_serializing_if = "Option::is_none", with = "id::option")]
+    project_id: Option<ProjectId>,
+    #[serde(skip_serializing_if = "Option::is_none", with = "id::option")]
+    section_id: Option<SectionId>
+}
+
+/// Request body renaming a section.
+#[derive(Serialize)]
+struct SectionUpdate<'a> {
+    name: &'a str
+}
+
+/// Client sending requests to the Todoist REST API on behalf of a user.
+pub struct TodoistClient {
+    /// API token of the user
+    token: String,
+    /// Base URL the endpoint paths are appended to
+    base_url: String,
+    /// Base URL the Sync API endpoint paths are appended to
+    sync_base_url: String,
+    /// Underlying HTTP client
+    http: Client,
+    /// Policy for retrying rate limited requests
+    retry_policy: RetryPolicy,
+    /// Metadata of the last response received, if any
+    last_response_meta: Mutex<Option<ResponseMeta>>
+}
+
+impl TodoistClient {
+    /// Creates a new client authenticating with the given API token.
+    ///
+    /// The token can be found in the Todoist settings under "Integrations". Rate limited requests
+    /// are not retried.
+    pub fn new(token: &str) -> TodoistClient {
+        TodoistClient::with_config(token, TodoistClientConfig::default())
+    }
+
+    /// Creates a new client authenticating with the given API token and using the given
+    /// configuration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the TLS backend of the HTTP client cannot be initialized, as `TodoistClient::new`
+    /// does.
+    pub fn with_config(token: &str, config: TodoistClientConfig) -> TodoistClient {
+        let mut http = Client::builder();
+
+        if let Some(timeout_secs) = config.timeout_secs {
+            http = http.timeout(Duration::from_secs(timeout_secs));
+        }
+
+        TodoistClient {
+            token: String::from(token),
+            base_url: config.base_url,
+            sync_base_url: config.sync_base_url,
+            http: http.build().expect("the TLS backend could not be initialized"),
+            retry_policy: config.retry_policy,
+            last_response_meta: Mutex::new(None)
+        }
+    }
+
+    /// Creates a new client authenticating with the API token in the `TODOIST_API_TOKEN`
+    /// environment variable.
+    ///
+    /// Rate limited requests are not retried.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TodoistError::MissingToken` if the variable is not set, is empty or is not valid
+    /// unicode.
+    pub fn from_env() -> Result<TodoistClient, TodoistError> {
+        match env::var(TOKEN_VAR) {
+            Ok(ref token) if !token.trim().is_empty() => Ok(TodoistClient::new(token.trim())),
+            _ => Err(TodoistError::MissingToken)
+        }
+    }
+
+    /// Creates a new client authenticating with the given API token and retrying rate limited
+    /// requests according to the given policy.
+    pub fn with_retry_policy(token: &str, retry_policy: RetryPolicy) -> TodoistClient {
+        let mut config = TodoistClientConfig::default();
+        config.set_retry_policy(retry_policy);
+        TodoistClient::with_config(token, config)
+    }
+
+    /// Creates a new client sending its requests to the given base URL.
+    #[cfg(test)]
+    fn with_base_url(token: &str, base_url: &str) -> TodoistClient {
+        let mut config = TodoistClientConfig::default();
+        config.set_base_url(base_url);
+        TodoistClient::with_config(token, config)
+    }
+
+    /// Gets a page of the active tasks, optionally narrowed down by a query.
+    ///
+    /// Pass `None` as the cursor to get the first page, and the `next_cursor` of a page to get
+    /// the page following it.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TodoistError` if the request fails or the API responds with an error.
+    pub fn get_all_tasks(&self, query: Option<&TaskQuery>, cursor: Option<&str>)
+            -> Result<Page<Task>, TodoistError> {
+        self.get_task_page(query, cursor, None)
+    }
+
+    /// Gets an iterator over the pages of the active tasks, optionally narrowed down by a query,
+    /// with at most `page_size` tasks per page.
+    ///
+    /// Pages are fetched one at a time as the iterator advances, so stopping early avoids
+    /// fetching the remaining pages. The iteration ends after the first failed request.
+    pub fn get_tasks_paged(&self, query: Option<&TaskQuery>, page_size: u32) -> TaskPages<'_> {
+        TaskPages::new(self, query.cloned(), page_size)
+    }
+
+    /// Gets the active tasks of all pages, optionally narrowed down by a query.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TodoistError` if any of the requests fails or the API responds with an error.
+    pub fn fetch_all_tasks(&self, query: Option<&TaskQuery>) -> Result<Vec<Task>, TodoistError> {
+        TodoistApi::fetch_all_tasks(self, query)
+    }
+
+    /// Gets the task with the given identifier.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TodoistError::NotFound` if there is no such task, or another `TodoistError` if
+    /// the request fails or the API responds with an error.
+    pub fn get_task(&self, id: TaskId) -> Result<Task, TodoistError> {
+        self.send(self.request(Method::GET, &format!("/tasks/{}", id)))
+    }
+
+    /// Creates a new task and returns it as stored by the server.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TodoistError` if the request fails or the API responds with an error.
+    pub fn create_task(&self, task: &Task) -> Result<Task, TodoistError> {
+        self.send(self.request(Method::POST, "/tasks").json(task))
+    }
+
+    /// Adds a task the way the quick add of the Todoist apps does and returns it as stored by the
+    /// server.
+    ///
+    /// The server parses the due date, project, labels and priority from the text, as in
+    /// "Buy milk tomorrow at 5pm #Groceries p2".
+    ///
+    /// The REST API has no quick add endpoint, so the request is sent to the `/quick/add`
+    /// endpoint of the Sync API.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TodoistError` if the request fails or the API responds with an error.
+    pub fn quick_add(&self, text: &str) -> Result<Task, TodoistError> {
+        self.send(self.sync_request(Method::POST, "/quick/add").json(&QuickAdd { text }))
+    }
+
+    /// Updates a task that was fetched or created before, using its identifier.
+    ///
+    /// Only the fields modified since the task was fetched or its changes were last reset are
+    /// sent, as returned by `Task::update_payload`, so changes made elsewhere are kept.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TodoistError::InvalidArgument` if the task has no identifier,
+    /// `TodoistError::NotFound` if there is no such task, or another `TodoistError` if the
+    /// request fails or the API responds with an error.
+    pub fn update_task(&self, task: &Task) -> Result<(), TodoistError> {
+        let id = task.id().ok_or_else(missing_task_id)?;
+        self.send_empty(self.request(Method::POST, &format!("/tasks/{}", id))
+            .json(&task.update_payload()))
+    }
+
+    /// Deletes the task with the given identifier.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TodoistError::NotFound` if there is no such task, or another `TodoistError` if
+    /// the request fails or the API responds with an error.
+    pub fn delete_task(&self, id: TaskId) -> Result<(), TodoistError> {
+        self.send_empty(self.request(Method::DELETE, &format!("/tasks/{}", id)))
+    }
+
+    /// Closes (completes) the task with the given identifier.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TodoistError::NotFound` if there is no such task, or another `TodoistError` if
+    /// the request fails or the API responds with an error.
+    pub fn close_task(&self, id: TaskId) -> Result<(), TodoistError> {
+        self.send_empty(self.request(Method::POST, &format!("/tasks/{}/close", id)))
+    }
+
+    /// Reopens (uncompletes) the task with the given identifier.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TodoistError::NotFound` if there is no such task, or another `TodoistError` if
+    /// the request fails or the API responds with an error.
+    pub fn reopen_task(&self, id: TaskId) -> Result<(), TodoistError> {
+        self.send_empty(self.request(Method::POST, &format!("/tasks/{}/reopen", id)))
+    }
+
+    /// Moves the task with the given identifier to another project.
+    ///
+    /// Only the project is sent, so other changes made to the task elsewhere are left untouched.
+    /// The server does not send the moved task back; use `get_task` to fetch it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TodoistError::NotFound` if there is no such task, or another `TodoistError` if
+    /// the request fails or the API responds with an error.
+    pub fn move_task(&self, id: TaskId, project_id: ProjectId) -> Result<(), TodoistError> {
+        let body = TaskMove { project_id: Some(project_id), section_id: None };
+        self.send_empty(self.request(Method::POST, &format!("/tasks/{}", id)).json(&body))
+    }
+
+    /// Moves the task with the given identifier to a section.
+    ///
+    /// Only the section is sent, so other changes made to the task elsewhere are left untouched.
+    /// The server does not send the moved task back; use `get_task` to fetch it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TodoistError::NotFound` if there is no such task, or another `TodoistError` if
+    /// the request fails or the API responds with an error.
+    pub fn move_task_to_section(&self, id: TaskId, section_id: SectionId)
+            -> Result<(), TodoistError> {
+        let body = TaskMove { project_id: None, section_id: Some(section_id) };
+        self.send_empty(self.request(Method::POST, &format!("/tasks/{}", id)).json(&body))
+    }
+
+    /// Gets a page of all projects.
+    ///
+    /// Pass `None` as the cursor to get the first page, and the `next_cursor` of a page to get
+    /// the page following it.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TodoistError` if the request fails or the API responds with an error.
+    pub fn get_all_projects(&self, cursor: Option<&str>) -> Result<Page<Project>, TodoistError> {
+        self.send_page(self.request(Method::GET, "/projects"), cursor)
+    }
+
+    /// Gets the project with the given identifier.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TodoistError::NotFound` if there is no such project, or another `TodoistError`
+    /// if the request fails or the API responds with an error.
+    pub fn get_project(&self, id: ProjectId) -> Result<Project, TodoistError> {
+        self.send(self.request(Method::GET, &format!("/projects/{}", id)))
+    }
+
+    /// Creates a new project and returns it as stored by the server.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TodoistError` if the request fails or the API responds with an error.
+    pub fn create_project(&self, project: &Project) -> Result<Project, TodoistError> {
+        self.send(self.request(Method::POST, "/projects").json(project))
+    }
+
+    /// Updates the project with the given identifier, sending the body given by
+    /// `Project::to_update_json`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TodoistError::NotFound` if there is no such project, or another `TodoistError`
+    /// if the request fails or the API responds with an error.
+    pub fn update_project(&self, id: ProjectId, project: &Project) -> Result<(), TodoistError> {
+        let request = self.request(Method::POST, &format!("/projects/{}", id));
+        self.send_empty(request.json(&project.update_body()))
+    }
+
+    /// Deletes the project with the given identifier, along with its tasks.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TodoistError::NotFound` if there is no such project, or another `TodoistError`
+    /// if the request fails or the API responds with an error.
+    pub fn delete_project(&self, id: ProjectId) -> Result<(), TodoistError> {
+        self.send_empty(self.request(Method::DELETE, &format!("/projects/{}", id)))
+    }
+
+    /// Gets the users collaborating on a shared project.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TodoistError::NotFound` if there is no such project, or another `TodoistError`
+    /// if the request fails or the API responds with an error.
+    pub fn get_all_collaborators(&self, project_id: ProjectId)
+            -> Result<Vec<Collaborator>, TodoistError> {
+        self.send(self.request(Method::GET, &format!("/projects/{}/collaborators", project_id)))
+    }
+
+    /// Gets all labels.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TodoistError` if the request fails or the API responds with an error.
+    pub fn get_all_labels(&self) -> Result<Vec<Label>, TodoistError> {
+        self.send(self.request(Method::GET, "/labels"))
+    }
+
+    /// Gets the label with the given identifier.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TodoistError::NotFound` if there is no such label, or another `TodoistError` if
+    /// the request fails or the API responds with an error.
+    pub fn get_label(&self, id: LabelId) -> Result<Label, TodoistError> {
+        self.send(self.request(Method::GET, &format!("/labels/{}", id)))
+    }
+
+    /// Creates a new label and returns it as stored by the server.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TodoistError` if the request fails or the API responds with an error.
+    pub fn create_label(&self, label: &Label) -> Result<Label, TodoistError> {
+        self.send(self.request(Method::POST, "/labels").json(label))
+    }
+
+    /// Updates the label with the given identifier.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TodoistError::NotFound` if there is no such label, or another `TodoistError` if
+    /// the request fails or the API responds with an error.
+    pub fn update_label(&self, id: LabelId, label: &Label) -> Result<(), TodoistError> {
+        self.send_empty(self.request(Method::POST, &format!("/labels/{}", id)).json(label))
+    }
+
+    /// Deletes the label with the given identifier.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TodoistError::NotFound` if there is no such label, or another `TodoistError` if
+    /// the request fails or the API responds with an error.
+    pub fn delete_label(&self, id: LabelId) -> Result<(), TodoistError> {
+        self.send_empty(self.request(Method::DELETE, &format!("/labels/{}", id)))
+    }
+
+    /// Gets all comments of a task or a project.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TodoistError` if the request fails or the API responds with an error.
+    pub fn get_comments(&self, filter: CommentFilter) -> Result<Vec<Comment>, TodoistError> {
+        self.send(self.request(Method::GET, "/comments").query(&filter.query_pairs()))
+    }
+
+    /// Gets all comments of a task.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TodoistError` if the request fails or the API responds with an error.
+    pub fn get_task_comments(&self, task_id: TaskId) -> Result<Vec<Comment>, TodoistError> {
+        self.get_comments(CommentFilter::for_task(task_id))
+    }
+
+    /// Gets all comments of a project.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TodoistError` if the request fails or the API responds with an error.
+    pub fn get_project_comments(&self, project_id: ProjectId)
+            -> Result<Vec<Comment>, TodoistError> {
+        self.get_comments(CommentFilter::for_project(project_id))
+    }
+
+    /// Gets the comment with the given identifier.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TodoistError::NotFound` if there is no such comment, or another `TodoistError` if
+    /// the request fails or the API responds with an error.
+    pub fn get_comment(&self, id: CommentId) -> Result<Comment, TodoistError> {
+        self.send(self.request(Method::GET, &format!("/comments/{}", id)))
+    }
+
+    /// Posts a new comment on its task or project and returns it as stored by the server.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TodoistError` if the request fails or the API responds with an error.
+    pub fn create_comment(&self, comment: &Comment) -> Result<Comment, TodoistError> {
+        self.send(self.request(Method::POST, "/comments").json(comment))
+    }
+
+    /// Updates the content of the comment with the given identifier.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TodoistError::NotFound` if there is no such comment, or another `TodoistError` if
+    /// the request fails or the API responds with an error.
+    pub fn update_comment(&self, id: CommentId, content: &str) -> Result<(), TodoistError> {
+        let body = CommentUpdate { content };
+        self.send_empty(self.request(Method::POST, &format!("/comments/{}", id)).json(&body))
+    }
+
+    /// Deletes the comment with the given identifier.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TodoistError::NotFound` if there is no such comment, or another `TodoistError` if
+    /// the request fails or the API responds with an error.
+    pub fn delete_comment(&self, id: CommentId) -> Result<(), TodoistError> {
+        self.send_empty(self.request(Method::DELETE, &format!("/comments/{}", id)))
+    }
+
+    /// Gets all sections, or only the sections of the given project.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TodoistError` if the request fails or the API responds with an error.
+    pub fn get_sections(&self, project_id: Option<ProjectId>)
+            -> Result<Vec<Section>, TodoistError> {
+        let mut request = self.request(Method::GET, "/sections");
+
+        if let Some(project_id) = project_id {
+            request = request.query(&[("project_id", project_id)]);
+        }
+
+        self.send(request)
+    }
+
+    /// Gets the section with the given identifier.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TodoistError::NotFound` if there is no such section, or another `TodoistError` if
+    /// the request fails or the API responds with an error.
+    pub fn get_section(&self, id: SectionId) -> Result<Section, TodoistError> {
+        self.send(self.request(Method::GET, &format!("/sections/{}", id)))
+    }
+
+    /// Creates a new section and returns it as stored by the server.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TodoistError` if the request fails or the API responds with an error.
+    pub fn create_section(&self, section: &Section) -> Result<Section, TodoistError> {
+        self.send(self.request(Method::POST, "/sections").json(section))
+    }
+
+    /// Renames the section with the given identifier.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TodoistError::NotFound` if there is no such section, or another `TodoistError` if
+    /// the request fails or the API responds with an error.
+    pub fn update_section(&self, id: SectionId, name: &str) -> Result<(), TodoistError> {
+        let body = SectionUpdate { name };
+        self.send_empty(self.request(Method::POST, &format!("/sections/{}", id)).json(&body))
+    }
+
+    /// Deletes the section with the given identifier, along with its tasks.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TodoistError::NotFound` if there is no such section, or another `TodoistError` if
+    /// the request fails or the API responds with an error.
+    pub fn delete_section(&self, id: SectionId) -> Result<(), TodoistError> {
+        self.send_empty(self.request(Method::DELETE, &format!("/sections/{}", id)))
+    }
+
+    /// Gets all reminders of a task.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TodoistError` if the request fails or the API responds with an error.
+    pub fn get_all_reminders(&self, task_id: TaskId) -> Result<Vec<Reminder>, TodoistError> {
+        self.send(self.request(Method::GET, "/reminders").query(&[("task_id", task_id)]))
+    }
+
+    /// Creates a new reminder and returns it as stored by the server.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TodoistError` if the request fails or the API responds with an error.
+    pub fn create_reminder(&self, reminder: &Reminder) -> Result<Reminder, TodoistError> {
+        self.send(self.request(Method::POST, "/reminders").json(reminder))
+    }
+
+    /// Updates the reminder with the given identifier.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TodoistError::NotFound` if there is no such reminder, or another `TodoistError`
+    /// if the request fails or the API responds with an error.
+    pub fn update_reminder(&self, id: ReminderId, reminder: &Reminder) -> Result<(), TodoistError> {
+        self.send_empty(self.request(Method::POST, &format!("/reminders/{}", id)).json(reminder))
+    }
+
+    /// Deletes the reminder with the given identifier.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TodoistError::NotFound` if there is no such reminder, or another `TodoistError`
+    /// if the request fails or the API responds with an error.
+    pub fn delete_reminder(&self, id: ReminderId) -> Result<(), TodoistError> {
+        self.send_empty(self.request(Method::DELETE, &format!("/reminders/{}", id)))
+    }
+
+    /// Gets a page of the active tasks with at most `limit` tasks, or the default page size of the
+    /// API if there is no limit.
+    fn get_task_page(&self, query: Option<&TaskQuery>, cursor: Option<&str>, limit: Option<u32>)
+            -> Result<Page<Task>, TodoistError> {
+        let mut request = self.request(Method::GET, "/tasks");
+
+        if let Some(query) = query {
+            request = request.query(&query.query_pairs());
+        }
+
+        if let Some(limit) = limit {
+            request = request.query(&[("limit", limit)]);
+        }
+
+        self.send_page(request, cursor)
+    }
+
+    /// Gets the metadata of the response to the last request sent by this client, or `None` if no
+    /// request was sent yet or the last one failed without a response, i.e. on a timeout.
+    ///
+    /// The metadata is recorded for unsuccessful responses as well, so it can be looked up after
+    /// a method returned an error. When the client is shared between threads, it belongs to
+    /// whichever request completed last.
+    pub fn last_response_meta(&self) -> Option<ResponseMeta> {
+        self.last_response_meta.lock().unwrap_or_else(|error| error.into_inner()).clone()
+    }
+
+    /// Starts an authenticated request to the given endpoint path.
+    fn request(&self, method: Method, path: &str) -> RequestBuilder {
+        self.http.request(method, format!("{}{}", self.base_url, path))
+            .bearer_auth(&self.token)
+    }
+
+    /// Starts an authenticated request to the given endpoint path of the Sync API.
+    fn sync_request(&self, method: Method, path: &str) -> RequestBuilder {
+        self.http.request(method, format!("{}{}", self.sync_base_url, path))
+            .bearer_auth(&self.token)
+    }
+
+    /// Sends a request and deserializes the JSON body of a successful response.
+    fn send<T: DeserializeOwned>(&self, request: RequestBuilder) -> Result<T, TodoistError> {
+        let body = self.execute(request)?.text()?;
+        Ok(serde_json::from_str(&body)?)
+    }
+
+    /// Sends a request for the page at the given cursor and deserializes its items.
+    fn send_page<T: DeserializeOwned>(&self, mut request: RequestBuilder, cursor: Option<&str>)
+            -> Result<Page<T>, TodoistError> {
+        if let Some(cursor) = cursor {
+            request = request.query(&[("cursor", cursor)]);
+        }
+
+        let response = self.execute(request)?;
+        let next_cursor = response.headers().get(NEXT_CURSOR_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .filter(|cursor| !cursor.is_empty())
+            .map(String::from);
+        let items = serde_json::from_str(&response.text()?)?;
+
+        Ok(Page::new(items, next_cursor))
+    }
+
+    /// Sends a request expecting a successful response without content (i.e. 204).
+    fn send_empty(&self, request: RequestBuilder) -> Result<(), TodoistError> {
+        self.execute(request)?;
+        Ok(())
+    }
+
+    /// Sends a request, retrying it according to the retry policy while it is rate limited if it
+    /// is a GET request, and returns the successful response.
+    fn execute(&self, request: RequestBuilder) -> Result<Response, TodoistError> {
+        let mut request = request.build()?;
+        let mut attempts = 1;
+        let started = Instant::now();
+        self.record_response_meta(None);
+
+        loop {
+            let retry = if request.method() == Method::GET { request.try_clone() } else { None };
+            let response = self.http.execute(request)?;
+            let request_id = response.headers().get(REQUEST_ID_HEADER)
+                .and_then(|value| value.to_str().ok())
+                .map(String::from);
+            self.record_response_meta(Some(ResponseMeta::new(response.status().as_u16(),
+                request_id, attempts, started.elapsed())));
+
+            if response.status() != StatusCode::TOO_MANY_REQUESTS {
+                return TodoistClient::check(response);
+            }
+
+            let retry_after = retry_after(response.headers());
+
+            match (self.retry_policy.delay(attempts, retry_after), retry) {
+                (Some(delay), Some(retry)) => {
+                    thread::sleep(delay);
+                    request = retry;
+                    attempts += 1;
+                },
+                _ => return Err(TodoistError::RateLimited { retry_after })
+            }
+        }
+    }
+
+    /// Replaces the metadata of the last response.
+    fn record_response_meta(&self, meta: Option<ResponseMeta>) {
+        *self.last_response_meta.lock().unwrap_or_else(|error| error.into_inner()) = meta;
+    }
+
+    /// Turns an unsuccessful response into the matching error.
+    fn check(response: Response) -> Result<Response, TodoistError> {
+        let status = response.status();
+
+        if status.is_success() {
+            Ok(response)
+        } else if status == StatusCode::NOT_FOUND {
+            Err(TodoistError::NotFound)
+        } else {
+            Err(TodoistError::Api { status: status.as_u16(), message: response.text()? })
+        }
+    }
+}
+
+/// Delegates to the inherent methods of the client, which take precedence in method calls.
+impl TodoistApi for TodoistClient {
+    fn get_all_tasks(&self, query: Option<&TaskQuery>, cursor: Option<&str>)
+            -> Result<Page<Task>, TodoistError> {
+        TodoistClient::get_all_tasks(self, query, cursor)
+    }
+
+    fn get_task(&self, id: TaskId) -> Result<Task, TodoistError> {
+        TodoistClient::get_task(self, id)
+    }
+
+    fn create_task(&self, task: &Task) -> Result<Task, TodoistError> {
+        TodoistClient::create_task(self, task)
+    }
+
+    fn quick_add(&self, text: &str) -> Result<Task, TodoistError> {
+        TodoistClient::quick_add(self, text)
+    }
+
+    fn update_task(&self, task: &Task) -> Result<(), TodoistError> {
+        TodoistClient::update_task(self, task)
+    }
+
+    fn delete_task(&self, id: TaskId) -> Result<(), TodoistError> {
+        TodoistClient::delete_task(self, id)
+    }
+
+    fn close_task(&self, id: TaskId) -> Result<(), TodoistError> {
+        TodoistClient::close_task(self, id)
+    }
+
+    fn reopen_task(&self, id: TaskId) -> Result<(), TodoistError> {
+        TodoistClient::reopen_task(self, id)
+    }
+
+    fn move_task(&self, id: TaskId, project_id: ProjectId) -> Result<(), TodoistError> {
+        TodoistClient::move_task(self, id, project_id)
+    }
+
+    fn move_task_to_section(&self, id: TaskId, section_id: SectionId)
+            -> Result<(), TodoistError> {
+        TodoistClient::move_task_to_section(self, id, section_id)
+    }
+
+    fn get_all_projects(&self, cursor: Option<&str>) -> Result<Page<Project>, TodoistError> {
+        TodoistClient::get_all_projects(self, cursor)
+    }
+
+    fn get_project(&self, id: ProjectId) -> Result<Project, TodoistError> {
+        TodoistClient::get_project(self, id)
+    }
+
+    fn create_project(&self, project: &Project) -> Result<Project, TodoistError> {
+        TodoistClient::create_project(self, project)
+    }
+
+    fn update_project(&self, id: ProjectId, project: &Project) -> Result<(), TodoistError> {
+        TodoistClient::update_project(self, id, project)
+    }
+
+    fn delete_project(&self, id: ProjectId) -> Result<(), TodoistError> {
+        TodoistClient::delete_project(self, id)
+    }
+
+    fn get_all_collaborators(&self, project_id: ProjectId)
+            -> Result<Vec<Collaborator>, TodoistError> {
+        TodoistClient::get_all_collaborators(self, project_id)
+    }
+
+    fn get_all_labels(&self) -> Result<Vec<Label>, TodoistError> {
+        TodoistClient::get_all_labels(self)
+    }
+
+    fn get_label(&self, id: LabelId) -> Result<Label, TodoistError> {
+        TodoistClient::get_label(self, id)
+    }
+
+    fn create_label(&self, label: &Label) -> Result<Label, TodoistError> {
+        TodoistClient::create_label(self, label)
+    }
+
+    fn update_label(&self, id: LabelId, label: &Label) -> Result<(), TodoistError> {
+        TodoistClient::update_label(self, id, label)
+    }
+
+    fn delete_label(&self, id: LabelId) -> Result<(), TodoistError> {
+        TodoistClient::delete_label(self, id)
+    }
+
+    fn get_comments(&self, filter: CommentFilter) -> Result<Vec<Comment>, TodoistError> {
+        TodoistClient::get_comments(self, filter)
+    }
+
+    fn get_comment(&self, id: CommentId) -> Result<Comment, TodoistError> {
+        TodoistClient::get_comment(self, id)
+    }
+
+    fn create_comment(&self, comment: &Comment) -> Result<Comment, TodoistError> {
+        TodoistClient::create_comment(self, comment)
+    }
+
+    fn update_comment(&self, id: CommentId, content: &str) -> Result<(), TodoistError> {
+        TodoistClient::update_comment(self, id, content)
+    }
+
+    fn delete_comment(&self, id: CommentId) -> Result<(), TodoistError> {
+        TodoistClient::delete_comment(self, id)
+    }
+
+    fn get_sections(&self, project_id: Option<ProjectId>) -> Result<Vec<Section>, TodoistError> {
+        TodoistClient::get_sections(self, project_id)
+    }
+
+    fn get_section(&self, id: SectionId) -> Result<Section, TodoistError> {
+        TodoistClient::get_section(self, id)
+    }
+
+    fn create_section(&self, section: &Section) -> Result<Section, TodoistError> {
+        TodoistClient::create_section(self, section)
+    }
+
+    fn update_section(&self, id: SectionId, name: &str) -> Result<(), TodoistError> {
+        TodoistClient::update_section(self, id, name)
+    }
+
+    fn delete_section(&self, id: SectionId) -> Result<(), TodoistError> {
+        TodoistClient::delete_section(self, id)
+    }
+
+    fn get_all_reminders(&self, task_id: TaskId) -> Result<Vec<Reminder>, TodoistError> {
+        TodoistClient::get_all_reminders(self, task_id)
+    }
+
+    fn create_reminder(&self, reminder: &Reminder) -> Result<Reminder, TodoistError> {
+        TodoistClient::create_reminder(self, reminder)
+    }
+
+    fn update_reminder(&self, id: ReminderId, reminder: &Reminder) -> Result<(), TodoistError> {
+        TodoistClient::update_reminder(self, id, reminder)
+    }
+
+    fn delete_reminder(&self, id: ReminderId) -> Result<(), TodoistError> {
+        TodoistClient::delete_reminder(self, id)
+    }
+}
+
+/// Gets the error returned when updating a task that has no identifier.
+fn missing_task_id() -> TodoistError {
+    TodoistError::InvalidArgument(
+        String::from("the task has no identifier, it must be fetched or created first"))
+}
+
+/// Gets the number of seconds to wait before retrying from the `Retry-After` header of a rate
+/// limited response.
+fn retry_after(headers: &HeaderMap) -> Option<u64> {
+    headers.get(RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<u64>().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate mockito;
+    use std::env;
+    use std::time::Duration;
+    use serde_json;
+    use self::mockito::Matcher;
+    use std::net::TcpListener;
+    use client::{TodoistClient, TodoistClientConfig, RetryPolicy, TOKEN_VAR};
+    use client::query::{TaskQuery, CommentFilter};
+    use error::TodoistError;
+    use model::task::{Task, Priority};
+    use model::project::Project;
+    use model::label::Label;
+    use model::comment::Comment;
+    use model::section::Section;
+    use model::reminder::{Reminder, ReminderType, ReminderTrigger};
+    use model::color::Color;
+
+    const TASKS: &str = r#"
+        [
+            {
+                "comment_count": 0,
+                "completed": false,
+                "content": "My task",
+                "id": 1234,
+                "label_ids": [124],
+                "order": 1,
+                "priority": 1,
+                "project_id": 2345
+            }
+        ]
+    "#;
+
+    #[test]
+    fn get_all_tasks() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("GET", "/tasks")
+            .match_header("authorization", "Bearer token")
+            .with_body(TASKS)
+            .create();
+
+        let client = TodoistClient::with_base_url("token", &server.url());
+        let page = client.get_all_tasks(None, None).unwrap();
+        mock.assert();
+        assert_eq!(page.items().len(), 1);
+        assert_eq!(page.items()[0].content(), "My task");
+        assert!(!page.has_next());
+    }
+
+    #[test]
+    fn get_all_tasks_with_query() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("GET", "/tasks")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded(String::from("project_id"), String::from("2345")),
+                Matcher::UrlEncoded(String::from("filter"), String::from("p1"))
+            ]))
+            .with_body(TASKS)
+            .create();
+
+        let mut query = TaskQuery::new();
+        query.set_project_id(Some(2345));
+        query.set_filter(Some("p1"));
+
+        let client = TodoistClient::with_base_url("token", &server.url());
+        let page = client.get_all_tasks(Some(&query), None).unwrap();
+        mock.assert();
+        assert_eq!(page.items()[0].project_id(), &Some(2345));
+    }
+
+    #[test]
+    fn fetch_all_tasks() {
+        let mut server = mockito::Server::new();
+        let first = server.mock("GET", "/tasks")
+            .match_query(Matcher::Missing)
+            .with_header("X-Next-Cursor", "abc")
+            .with_body(TASKS)
+            .create();
+        let second = server.mock("GET", "/tasks")
+            .match_query(Matcher::UrlEncoded(String::from("cursor"), String::from("abc")))
+            .with_body(r#"[{"content": "My other task", "completed": false, "label_ids": [],
+                            "priority": 4}]"#)
+            .create();
+
+        let client = TodoistClient::with_base_url("token", &server.url());
+        let tasks = client.fetch_all_tasks(None).unwrap();
+        first.assert();
+        second.assert();
+        assert_eq!(tasks.len(), 2);
+        assert_eq!(tasks[1].content(), "My other task");
+    }
+
+    #[test]
+    fn fetch_all_tasks_with_repeated_cursor() {
+        let mut server = mockito::Server::new();
+        let first = server.mock("GET", "/tasks")
+            .match_query(Matcher::Missing)
+            .with_header("X-Next-Cursor", "abc")
+            .with_body(TASKS)
+            .expect(1)
+            .create();
+        let repeated = server.mock("GET", "/tasks")
+            .match_query(Matcher::UrlEncoded(String::from("cursor"), String::from("abc")))
+            .with_header("X-Next-Cursor", "abc")
+            .with_body(r#"[{"content": "My other task", "priority": 4}]"#)
+            .expect(1)
+            .create();
+
+        let client = TodoistClient::with_base_url("token", &server.url());
+        let tasks = client.fetch_all_tasks(None).unwrap();
+        first.assert();
+        repeated.assert();
+        assert_eq!(tasks.len(), 2);
+    }
+
+    #[test]
+    fn fetch_all_tasks_with_empty_cursor() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("GET", "/tasks")
+            .match_query(Matcher::Missing)
+            .with_header("X-Next-Cursor", "")
+            .with_body(TASKS)
+            .expect(2)
+            .create();
+
+        let client = TodoistClient::with_base_url("token", &server.url());
+        assert!(!client.get_all_tasks(None, None).unwrap().has_next());
+        assert_eq!(client.fetch_all_tasks(None).unwrap().len(), 1);
+        mock.assert();
+    }
+
+    #[test]
+    fn get_tasks_paged() {
+        let mut server = mockito::Server::new();
+        let first = server.mock("GET", "/tasks")
+            .match_query(Matcher::Exact(String::from("project_id=2345&limit=2")))
+            .with_header("X-Next-Cursor", "abc")
+            .with_body(r#"[{"id": 1, "content": "Task 1"}, {"id": 2, "content": "Task 2"}]"#)
+            .expect(2)
+            .create();
+        let second = server.mock("GET", "/tasks")
+            .match_query(Matcher::Exact(String::from("project_id=2345&limit=2&cursor=abc")))
+            .with_body(r#"[{"id": 3, "content": "Task 3"}]"#)
+            .expect(1)
+            .create();
+
+        let mut query = TaskQuery::new();
+        query.set_project_id(Some(2345));
+
+        let client = TodoistClient::with_base_url("token", &server.url());
+        let pages: Vec<Vec<Task>> = client.get_tasks_paged(Some(&query), 2)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(pages.len(), 2);
+        assert_eq!(pages[0].len(), 2);
+        assert_eq!(pages[1][0].content(), "Task 3");
+
+        let mut pages = client.get_tasks_paged(Some(&query), 2);
+        assert_eq!(pages.next().unwrap().unwrap().len(), 2);
+        first.assert();
+        second.assert();
+    }
+
+    #[test]
+    fn close_task() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("POST", "/tasks/1234/close")
+            .match_header("authorization", "Bearer token")
+            .match_body("")
+            .with_status(204)
+            .create();
+
+        let client = TodoistClient::with_base_url("token", &server.url());
+        client.close_task(1234).unwrap();
+        mock.assert();
+    }
+
+    #[test]
+    fn reopen_task() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("POST", "/tasks/1234/reopen")
+            .match_header("authorization", "Bearer token")
+            .match_body("")
+            .with_status(204)
+            .create();
+
+        let client = TodoistClient::with_base_url("token", &server.url());
+        client.reopen_task(1234).unwrap();
+        mock.assert();
+    }
+
+    #[test]
+    fn move_task() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("POST", "/tasks/1234")
+            .match_body(Matcher::JsonString(String::from(r#"{"project_id": 3456}"#)))
+            .with_status(204)
+            .create();
+
+        let client = TodoistClient::with_base_url("token", &server.url());
+        client.move_task(1234, 3456).unwrap();
+        mock.assert();
+    }
+
+    #[test]
+    fn move_task_to_section() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("POST", "/tasks/1234")
+            .match_body(Matcher::JsonString(String::from(r#"{"section_id": 7025}"#)))
+            .with_status(204)
+            .create();
+
+        let client = TodoistClient::with_base_url("token", &server.url());
+        client.move_task_to_section(1234, 7025).unwrap();
+        mock.assert();
+    }
+
+    #[test]
+    fn get_all_projects() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("GET", "/projects")
+            .match_query(Matcher::UrlEncoded(String::from("cursor"), String::from("abc")))
+            .with_header("X-Next-Cursor", "def")
+            .with_body(r#"[{"id": 2345, "name": "Inbox", "is_inbox_project": true}]"#)
+            .create();
+
+        let client = TodoistClient::with_base_url("token", &server.url());
+        let page = client.get_all_projects(Some("abc")).unwrap();
+        mock.assert();
+        assert_eq!(page.items()[0].name(), "Inbox");
+        assert_eq!(page.next_cursor(), &Some(String::from("def")));
+    }
+
+    #[test]
+    fn create_and_get_task() {
+        let mut server = mockito::Server::new();
+        let create = server.mock("POST", "/tasks")
+            .match_body(Matcher::JsonString(String::from(r#"{"content": "Buy milk", "priority": 1}"#)))
+            .with_body(r#"{"id": 1234, "content": "Buy milk", "project_id": 2345}"#)
+            .create();
+        let get = server.mock("GET", "/tasks/1234")
+            .with_body(r#"{"id": 1234, "content": "Buy milk", "project_id": 2345}"#)
+            .create();
+
+        let client = TodoistClient::with_base_url("token", &server.url());
+        let task = client.create_task(&Task::create("Buy milk")).unwrap();
+        create.assert();
+        assert_eq!(task.id(), &Some(1234));
+
+        let task = client.get_task(1234).unwrap();
+        get.assert();
+        assert_eq!(task.project_id(), &Some(2345));
+    }
+
+    #[test]
+    fn quick_add() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("POST", "/sync/v9/quick/add")
+            .match_header("authorization", "Bearer token")
+            .match_body(Matcher::JsonString(
+                String::from(r#"{"text": "Buy milk tomorrow at 5pm #Groceries @errand p2"}"#)))
+            .with_body(r#"{"id": "1234", "content": "Buy milk", "project_id": "2345",
+                           "labels": ["errand"], "priority": 3,
+                           "due": {"string": "tomorrow at 5pm", "date": "2016-09-02",
+                                   "datetime": "2016-09-02T17:00:00Z"}}"#)
+            .create();
+
+        let mut config = TodoistClientConfig::new();
+        assert_eq!(config.sync_base_url(), "https://api.todoist.com/sync/v9");
+        config.set_base_url(&format!("{}/rest/v1", server.url()));
+        config.set_sync_base_url(&format!("{}/sync/v9/", server.url()));
+        let client = TodoistClient::with_config("token", config);
+        let task = client.quick_add("Buy milk tomorrow at 5pm #Groceries @errand p2").unwrap();
+        mock.assert();
+        assert_eq!(task.id(), &Some(1234));
+        assert_eq!(task.content(), "Buy milk");
+        assert_eq!(task.project_id(), &Some(2345));
+        assert_eq!(task.labels(), ["errand"]);
+        assert_eq!(task.priority(), Priority::High);
+        let due = task.due().clone().unwrap();
+        assert_eq!(due.string(), "tomorrow at 5pm");
+        assert_eq!(due.datetime(), Some(String::from("2016-09-02T17:00:00Z")));
+    }
+
+    #[test]
+    fn update_task() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("POST", "/tasks/1234")
+            .match_body(Matcher::JsonString(String::from(r#"{"content": "Buy oat milk",
+                                                             "priority": 4}"#)))
+            .with_status(204)
+            .create();
+
+        let mut task: Task = serde_json::from_str(r#"{"id": 1234, "content": "Buy milk",
+                                                      "project_id": 2345}"#).unwrap();
+        task.set_content("Buy oat milk");
+        task.set_priority(Priority::Urgent);
+
+        let client = TodoistClient::with_base_url("token", &server.url());
+        client.update_task(&task).unwrap();
+        mock.assert();
+    }
+
+    #[test]
+    fn update_task_without_id() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("POST", Matcher::Any)
+            .expect(0)
+            .create();
+
+        let client = TodoistClient::with_base_url("token", &server.url());
+        let error = client.update_task(&Task::create("Buy milk")).unwrap_err();
+        mock.assert();
+        match error {
+            TodoistError::InvalidArgument(message) => {
+                assert!(message.contains("fetched or created"))
+            },
+            error => panic!("unexpected error {:?}", error)
+        }
+    }
+
+    #[test]
+    fn delete_task() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("DELETE", "/tasks/1234")
+            .with_status(204)
+            .create();
+
+        let client = TodoistClient::with_base_url("token", &server.url());
+        client.delete_task(1234).unwrap();
+        mock.assert();
+    }
+
+    #[test]
+    fn create_get_update_and_delete_project() {
+        let mut server = mockito::Server::new();
+        let create = server.mock("POST", "/projects")
+            .match_body(Matcher::JsonString(String::from(r#"{"name": "Groceries"}"#)))
+            .with_body(r#"{"id": 2345, "name": "Groceries"}"#)
+            .create();
+        let get = server.mock("GET", "/projects/2345")
+            .with_body(r#"{"id": 2345, "name": "Groceries"}"#)
+            .create();
+        let update = server.mock("POST", "/projects/2345")
+            .match_body(Matcher::JsonString(
+                String::from(r#"{"name": "Shopping", "color": 30, "is_favorite": false}"#)))
+            .with_status(204)
+            .create();
+        let delete = server.mock("DELETE", "/projects/2345")
+            .with_status(204)
+            .create();
+
+        let client = TodoistClient::with_base_url("token", &server.url());
+        let project = client.create_project(&Project::create("Groceries")).unwrap();
+        create.assert();
+        assert_eq!(project.id(), &Some(2345));
+
+        let mut project = client.get_project(2345).unwrap();
+        get.assert();
+        project.set_name("Shopping");
+        client.update_project(2345, &project).unwrap();
+        update.assert();
+
+        client.delete_project(2345).unwrap();
+        delete.assert();
+    }
+
+    #[test]
+    fn get_all_collaborators() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("GET", "/projects/2345/collaborators")
+            .with_body(r#"[{"id": 2671355, "name": "Alice", "email": "alice@example.com"},
+                           {"id": 2671366, "name": "Bob", "email": "bob@example.com"}]"#)
+            .create();
+
+        let client = TodoistClient::with_base_url("token", &server.url());
+        let collaborators = client.get_all_collaborators(2345).unwrap();
+        mock.assert();
+        assert_eq!(collaborators.len(), 2);
+        assert_eq!(collaborators[1].name(), "Bob");
+    }
+
+    #[test]
+    fn get_all_tasks_with_api_error() {
+        let mut server = mockito::Server::new();
+        server.mock("GET", "/tasks")
+            .with_status(400)
+            .with_body("Invalid filter")
+            .create();
+
+        let client = TodoistClient::with_base_url("token", &server.url());
+        match client.get_all_tasks(None, None) {
+            Err(TodoistError::Api { status, message }) => {
+                assert_eq!(status, 400);
+                assert_eq!(message, "Invalid filter");
+            },
+            result => panic!("unexpected result {:?}", result)
+        }
+    }
+
+    #[test]
+    fn get_all_labels() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("GET", "/labels")
+            .with_body(r#"[{"id": 124, "name": "Food", "color": 31, "order": 1, "favorite": false},
+                           {"id": 125, "name": "Shopping", "color": 32, "order": 2, "favorite": true}]"#)
+            .create();
+
+        let client = TodoistClient::with_base_url("token", &server.url());
+        let labels = client.get_all_labels().unwrap();
+        mock.assert();
+        assert_eq!(labels.len(), 2);
+        assert_eq!(labels[1].name(), "Shopping");
+        assert!(labels[1].favorite());
+    }
+
+    #[test]
+    fn get_label() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("GET", "/labels/124")
+            .with_body(r#"{"id": 124, "name": "Food", "color": 31, "order": 1, "favorite": false}"#)
+            .create();
+
+        let client = TodoistClient::with_base_url("token", &server.url());
+        let label = client.get_label(124).unwrap();
+        mock.assert();
+        assert_eq!(label.name(), "Food");
+        assert_eq!(label.color(), &Some(Color::Red));
+    }
+
+    #[test]
+    fn get_missing_label() {
+        let mut server = mockito::Server::new();
+        server.mock("GET", "/labels/999")
+            .with_status(404)
+            .create();
+
+        let client = TodoistClient::with_base_url("token", &server.url());
+        match client.get_label(999) {
+            Err(TodoistError::NotFound) => (),
+            result => panic!("unexpected result {:?}", result)
+        }
+    }
+
+    #[test]
+    fn create_label() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("POST", "/labels")
+            .match_body(Matcher::JsonString(String::from(r#"{"name": "Food", "favorite": false}"#)))
+            .with_body(r#"{"id": 124, "name": "Food", "color": 47, "order": 1, "favorite": false}"#)
+            .create();
+
+        let client = TodoistClient::with_base_url("token", &server.url());
+        let label = client.create_label(&Label::create("Food")).unwrap();
+        mock.assert();
+        assert_eq!(label.id(), &Some(124));
+        assert_eq!(label.order(), &Some(1));
+    }
+
+    #[test]
+    fn update_label() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("POST", "/labels/124")
+            .match_body(Matcher::JsonString(String::from(r#"{"name": "Groceries", "color": 32,
+                                                             "favorite": true}"#)))
+            .with_status(204)
+            .create();
+
+        let json = r#"{"id": 124, "name": "Food", "color": 31, "order": 1, "favorite": false}"#;
+        let mut label: Label = serde_json::from_str(json).unwrap();
+        label.set_name("Groceries");
+        label.set_color(Some(Color::Orange));
+        label.set_favorite(true);
+
+        let client = TodoistClient::with_base_url("token", &server.url());
+        client.update_label(124, &label).unwrap();
+        mock.assert();
+    }
+
+    #[test]
+    fn delete_label() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("DELETE", "/labels/124")
+            .with_status(204)
+            .create();
+
+        let client = TodoistClient::with_base_url("token", &server.url());
+        client.delete_label(124).unwrap();
+        mock.assert();
+    }
+
+    #[test]
+    fn get_comments() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("GET", "/comments")
+            .match_query(Matcher::UrlEncoded(String::from("project_id"), String::from("2345")))
+            .with_body(r#"[{"id": 1234, "project_id": 2345, "content": "Project plan",
+                            "posted": "2016-09-22T07:00:00Z"}]"#)
+            .create();
+
+        let client = TodoistClient::with_base_url("token", &server.url());
+        let comments = client.get_comments(CommentFilter::for_project(2345)).unwrap();
+        mock.assert();
+        assert_eq!(comments.len(), 1);
+        assert_eq!(comments[0].content(), "Project plan");
+    }
+
+    #[test]
+    fn get_task_comments() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("GET", "/comments")
+            .match_query(Matcher::Exact(String::from("task_id=1234")))
+            .with_body(r#"[{"id": 2992, "task_id": 1234, "content": "Need one bottle of milk",
+                            "posted": "2016-09-22T07:00:00Z"}]"#)
+            .create();
+
+        let client = TodoistClient::with_base_url("token", &server.url());
+        let comments = client.get_task_comments(1234).unwrap();
+        mock.assert();
+        assert_eq!(comments[0].task_id(), Some(1234));
+    }
+
+    #[test]
+    fn get_project_comments() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("GET", "/comments")
+            .match_query(Matcher::Exact(String::from("project_id=2345")))
+            .with_body(r#"[{"id": 2993, "project_id": 2345, "content": "Project plan",
+                            "posted": "2016-09-22T07:00:00Z"}]"#)
+            .create();
+
+        let client = TodoistClient::with_base_url("token", &server.url());
+        let comments = client.get_project_comments(2345).unwrap();
+        mock.assert();
+        assert_eq!(comments[0].project_id(), Some(2345));
+    }
+
+    #[test]
+    fn delete_comment() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("DELETE", "/comments/2992")
+            .with_status(204)
+            .create();
+
+        let client = TodoistClient::with_base_url("token", &server.url());
+        client.delete_comment(2992).unwrap();
+        mock.assert();
+    }
+
+    #[test]
+    fn create_comment() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("POST", "/comments")
+            .match_body(Matcher::JsonString(String::from(r#"{"content": "Need one bottle of milk",
+                                                             "task_id": 2345}"#)))
+            .with_body(r#"{"id": 1234, "task_id": 2345, "content": "Need one bottle of milk",
+                           "posted": "2016-09-22T07:00:00Z"}"#)
+            .create();
+
+        let comment = Comment::for_task(2345, "Need one bottle of milk");
+
+        let client = TodoistClient::with_base_url("token", &server.url());
+        let comment = client.create_comment(&comment).unwrap();
+        mock.assert();
+        assert_eq!(comment.id(), &Some(1234));
+        assert_eq!(comment.posted(), &Some(String::from("2016-09-22T07:00:00Z")));
+    }
+
+    #[test]
+    fn update_comment() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("POST", "/comments/1234")
+            .match_body(Matcher::JsonString(String::from(r#"{"content": "Need two bottles of milk"}"#)))
+            .with_status(204)
+            .create();
+
+        let client = TodoistClient::with_base_url("token", &server.url());
+        client.update_comment(1234, "Need two bottles of milk").unwrap();
+        mock.assert();
+    }
+
+    #[test]
+    fn retry_policy_delays() {
+        assert_eq!(RetryPolicy::NoRetry.delay(1, None), None);
+
+        let fixed = RetryPolicy::fixed(3, 5);
+        assert_eq!(fixed.delay(1, None), Some(Duration::from_secs(5)));
+        assert_eq!(fixed.delay(2, Some(10)), Some(Duration::from_secs(10)));
+        assert_eq!(fixed.delay(3, None), None);
+
+        let exponential = RetryPolicy::exponential(4, 2);
+        assert_eq!(exponential.delay(1, None), Some(Duration::from_secs(2)));
+        assert_eq!(exponential.delay(2, None), Some(Duration::from_secs(4)));
+        assert_eq!(exponential.delay(3, None), Some(Duration::from_secs(8)));
+        assert_eq!(exponential.delay(4, None), None);
+    }
+
+    #[test]
+    fn retry_policy_delays_capped() {
+        assert_eq!(RetryPolicy::fixed(2, 5).delay(1, Some(86_400)), Some(Duration::from_secs(60)));
+        assert_eq!(RetryPolicy::exponential(40, 1).delay(30, None), Some(Duration::from_secs(60)));
+
+        let policy = RetryPolicy::RetryFixed { max_attempts: 2, delay_secs: 5, max_delay_secs: 2 };
+        assert_eq!(policy.delay(1, None), Some(Duration::from_secs(2)));
+        assert_eq!(policy.delay(1, Some(u64::MAX)), Some(Duration::from_secs(2)));
+    }
+
+    #[test]
+    fn rate_limited_without_retry() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("GET", "/labels")
+            .with_status(429)
+            .with_header("Retry-After", "30")
+            .expect(1)
+            .create();
+
+        let client = TodoistClient::with_base_url("token", &server.url());
+        match client.get_all_labels() {
+            Err(TodoistError::RateLimited { retry_after }) => assert_eq!(retry_after, Some(30)),
+            result => panic!("unexpected result {:?}", result)
+        }
+        mock.assert();
+    }
+
+    #[test]
+    fn rate_limited_after_retries() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("GET", "/labels")
+            .with_status(429)
+            .expect(3)
+            .create();
+
+        let mut client = TodoistClient::with_base_url("token", &server.url());
+        client.retry_policy = RetryPolicy::exponential(3, 0);
+        match client.get_all_labels() {
+            Err(TodoistError::RateLimited { retry_after }) => assert_eq!(retry_after, None),
+            result => panic!("unexpected result {:?}", result)
+        }
+        mock.assert();
+    }
+
+    #[test]
+    fn retry_rate_limited_request() {
+        let mut server = mockito::Server::new();
+        let limited = server.mock("GET", "/labels")
+            .with_status(429)
+            .with_header("Retry-After", "0")
+            .expect(1)
+            .create();
+        let success = server.mock("GET", "/labels")
+            .with_body(r#"[{"id": 124, "name": "Food"}]"#)
+            .expect(1)
+            .create();
+
+        let client = TodoistClient::with_retry_policy("token",
+            RetryPolicy::fixed(2, 60));
+        let client = TodoistClient { base_url: server.url(), ..client };
+        let labels = client.get_all_labels().unwrap();
+        limited.assert();
+        success.assert();
+        assert_eq!(labels[0].name(), "Food");
+    }
+
+    #[test]
+    fn response_meta_of_retried_request() {
+        let mut server = mockito::Server::new();
+        let limited = server.mock("GET", "/labels")
+            .with_status(429)
+            .with_header("Retry-After", "1")
+            .with_header("X-Request-Id", "first-attempt")
+            .expect(1)
+            .create();
+        let success = server.mock("GET", "/labels")
+            .with_header("X-Request-Id", "9a8b7c6d")
+            .with_body("[]")
+            .expect(1)
+            .create();
+
+        let client = TodoistClient::with_retry_policy("token", RetryPolicy::fixed(2, 60));
+        let client = TodoistClient { base_url: server.url(), ..client };
+        assert_eq!(client.last_response_meta(), None);
+        client.get_all_labels().unwrap();
+        limited.assert();
+        success.assert();
+
+        let meta = client.last_response_meta().unwrap();
+        assert_eq!(meta.status(), 200);
+        assert_eq!(meta.request_id(), &Some(String::from("9a8b7c6d")));
+        assert_eq!(meta.attempts(), 2);
+        assert!(meta.duration() >= Duration::from_secs(1));
+
+        let mock = server.mock("GET", "/tasks/1234")
+            .with_status(404)
+            .create();
+        assert!(client.get_task(1234).is_err());
+        mock.assert();
+
+        let meta = client.last_response_meta().unwrap();
+        assert_eq!(meta.status(), 404);
+        assert_eq!(meta.request_id(), &None);
+        assert_eq!(meta.attempts(), 1);
+    }
+
+    #[test]
+    fn retry_after_capped_by_policy() {
+        let mut server = mockito::Server::new();
+        let limited = server.mock("GET", "/labels")
+            .with_status(429)
+            .with_header("Retry-After", "86400")
+            .expect(1)
+            .create();
+        let success = server.mock("GET", "/labels")
+            .with_body(r#"[{"id": 124, "name": "Food"}]"#)
+            .expect(1)
+            .create();
+
+        let mut client = TodoistClient::with_base_url("token", &server.url());
+        client.retry_policy =
+            RetryPolicy::RetryFixed { max_attempts: 2, delay_secs: 0, max_delay_secs: 0 };
+        let labels = client.get_all_labels().unwrap();
+        limited.assert();
+        success.assert();
+        assert_eq!(labels[0].name(), "Food");
+    }
+
+    #[test]
+    fn rate_limited_update_not_retried() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("POST", "/tasks/1234/close")
+            .with_status(429)
+            .expect(1)
+            .create();
+
+        let mut client = TodoistClient::with_base_url("token", &server.url());
+        client.retry_policy = RetryPolicy::exponential(3, 0);
+        match client.close_task(1234) {
+            Err(TodoistError::RateLimited { retry_after }) => assert_eq!(retry_after, None),
+            result => panic!("unexpected result {:?}", result)
+        }
+        mock.assert();
+    }
+
+    #[test]
+    fn server_error_not_retried() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("GET", "/labels")
+            .with_status(503)
+            .expect(1)
+            .create();
+
+        let mut client = TodoistClient::with_base_url("token", &server.url());
+        client.retry_policy = RetryPolicy::exponential(3, 0);
+        match client.get_all_labels() {
+            Err(TodoistError::Api { status, .. }) => assert_eq!(status, 503),
+            result => panic!("unexpected result {:?}", result)
+        }
+        mock.assert();
+    }
+
+    #[test]
+    fn get_sections() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("GET", "/sections")
+            .match_query(Matcher::UrlEncoded(String::from("project_id"), String::from("2345")))
+            .with_body(r#"[{"id": 7025, "project_id": 2345, "order": 1, "name": "Groceries"},
+                           {"id": 7026, "project_id": 2345, "order": 2, "name": "Chores"}]"#)
+            .create();
+
+        let client = TodoistClient::with_base_url("token", &server.url());
+        let sections = client.get_sections(Some(2345)).unwrap();
+        mock.assert();
+        assert_eq!(sections.len(), 2);
+        assert_eq!(sections[1].name(), "Chores");
+    }
+
+    #[test]
+    fn create_section() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("POST", "/sections")
+            .match_body(Matcher::JsonString(String::from(r#"{"project_id": 2345, "name": "Groceries"}"#)))
+            .with_body(r#"{"id": 7025, "project_id": 2345, "order": 1, "name": "Groceries"}"#)
+            .create();
+
+        let client = TodoistClient::with_base_url("token", &server.url());
+        let section = client.create_section(&Section::create("Groceries", 2345)).unwrap();
+        mock.assert();
+        assert_eq!(section.id(), &Some(7025));
+    }
+
+    #[test]
+    fn get_all_sections() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("GET", "/sections")
+            .match_query(Matcher::Exact(String::new()))
+            .with_body(r#"[{"id": 7025, "project_id": 2345, "order": 1, "name": "Groceries"},
+                           {"id": 8010, "project_id": 3456, "order": 1, "name": "Backlog"}]"#)
+            .create();
+
+        let client = TodoistClient::with_base_url("token", &server.url());
+        let sections = client.get_sections(None).unwrap();
+        mock.assert();
+        assert_eq!(sections.len(), 2);
+        assert_eq!(sections[1].project_id(), 3456);
+    }
+
+    #[test]
+    fn get_section() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("GET", "/sections/7025")
+            .with_body(r#"{"id": 7025, "project_id": 2345, "order": 1, "name": "Groceries"}"#)
+            .create();
+
+        let client = TodoistClient::with_base_url("token", &server.url());
+        let section = client.get_section(7025).unwrap();
+        mock.assert();
+        assert_eq!(section.name(), "Groceries");
+    }
+
+    #[test]
+    fn update_section() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("POST", "/sections/7025")
+            .match_body(Matcher::JsonString(String::from(r#"{"name": "Shopping"}"#)))
+            .with_status(204)
+            .create();
+
+        let client = TodoistClient::with_base_url("token", &server.url());
+        client.update_section(7025, "Shopping").unwrap();
+        mock.assert();
+    }
+
+    #[test]
+    fn delete_section() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("DELETE", "/sections/7025")
+            .with_status(204)
+            .create();
+
+        let client = TodoistClient::with_base_url("token", &server.url());
+        client.delete_section(7025).unwrap();
+        mock.assert();
+    }
+
+    #[test]
+    fn client_from_env() {
+        env::set_var(TOKEN_VAR, "0123456789abcdef");
+        let client = TodoistClient::from_env().unwrap();
+        assert_eq!(client.token, "0123456789abcdef");
+
+        env::set_var(TOKEN_VAR, "  ");
+        assert!(matches!(TodoistClient::from_env(), Err(TodoistError::MissingToken)));
+
+        env::remove_var(TOKEN_VAR);
+        assert!(matches!(TodoistClient::from_env(), Err(TodoistError::MissingToken)));
+    }
+
+    #[test]
+    fn get_all_reminders() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("GET", "/reminders")
+            .match_query(Matcher::Exact(String::from("task_id=1234")))
+            .with_body(r#"[{"id": 5678, "task_id": 1234, "type": "relative", "mm_offset": 30}]"#)
+            .create();
+
+        let client = TodoistClient::with_base_url("token", &server.url());
+        let reminders = client.get_all_reminders(1234).unwrap();
+        mock.assert();
+        assert_eq!(reminders[0].reminder_type(), ReminderType::Relative);
+        assert_eq!(reminders[0].mm_offset(), Some(30));
+    }
+
+    #[test]
+    fn create_reminder() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("POST", "/reminders")
+            .match_body(Matcher::JsonString(String::from(r#"{"task_id": 1234, "type": "relative",
+                                                             "mm_offset": 15}"#)))
+            .with_body(r#"{"id": 5678, "task_id": 1234, "type": "relative", "mm_offset": 15}"#)
+            .create();
+
+        let reminder = Reminder::create(1234, ReminderTrigger::Relative { mm_offset: 15 });
+
+        let client = TodoistClient::with_base_url("token", &server.url());
+        let reminder = client.create_reminder(&reminder).unwrap();
+        mock.assert();
+        assert_eq!(reminder.id(), &Some(5678));
+    }
+
+    #[test]
+    fn update_reminder() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("POST", "/reminders/5678")
+            .match_body(Matcher::JsonString(String::from(r#"{"task_id": 1234, "type": "relative",
+                                                             "mm_offset": 45}"#)))
+            .with_status(204)
+            .create();
+
+        let reminder = Reminder::create(1234, ReminderTrigger::Relative { mm_offset: 45 });
+
+        let client = TodoistClient::with_base_url("token", &server.url());
+        client.update_reminder(5678, &reminder).unwrap();
+        mock.assert();
+    }
+
+    #[test]
+    fn delete_reminder() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("DELETE", "/reminders/5678")
+            .with_status(204)
+            .create();
+
+        let client = TodoistClient::with_base_url("token", &server.url());
+        client.delete_reminder(5678).unwrap();
+        mock.assert();
+    }
+
+    #[test]
+    fn configure_base_url_and_timeout() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("GET", "/labels")
+            .with_body("[]")
+            .create();
+
+        let mut config = TodoistClientConfig::new();
+        config.set_base_url(&format!("{}/", server.url()));
+        assert_eq!(config.base_url(), server.url());
+        let client = TodoistClient::with_config("token", config);
+        assert!(client.get_all_labels().unwrap().is_empty());
+        mock.assert();
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let mut config = TodoistClientConfig::new();
+        config.set_base_url(&format!("http://{}", listener.local_addr().unwrap()));
+        config.set_timeout_secs(Some(1));
+        let client = TodoistClient::with_config("token", config);
+
+        match client.get_all_labels() {
+            Err(TodoistError::Http(error)) => assert!(error.is_timeout()),
+            result => panic!("unexpected result {:?}", result)
+        }
+    }
+}
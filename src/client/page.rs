@@ -0,0 +1,99 @@
+//! # Page
+//!
+//! Module containing structures for paginated client responses.
+
+use error::TodoistError;
+use model::task::Task;
+use client::TodoistClient;
+use client::query::TaskQuery;
+use client::api::following_cursor;
+
+/// A single page of items returned by a paginated endpoint.
+///
+/// Paginated endpoints return a cursor in the `X-Next-Cursor` response header when more items are
+/// available. Passing that cursor to the same endpoint returns the following page; a page without
+/// a cursor is the last one. Cursors are opaque and should only be passed back unchanged.
+#[derive(Debug, Clone)]
+pub struct Page<T> {
+    /// Items of this page
+    items: Vec<T>,
+    /// Cursor to request the following page with, if there is one
+    next_cursor: Option<String>
+}
+
+impl<T> Page<T> {
+    /// Creates a new page from its items and the cursor of the following page.
+    pub fn new(items: Vec<T>, next_cursor: Option<String>) -> Page<T> {
+        Page { items, next_cursor }
+    }
+
+    /// Gets the items of this page.
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    /// Gets the cursor to request the following page with, or `None` for the last page.
+    pub fn next_cursor(&self) -> &Option<String> {
+        &self.next_cursor
+    }
+
+    /// Gets whether there is a page following this one.
+    pub fn has_next(&self) -> bool {
+        self.next_cursor.is_some()
+    }
+
+    /// Consumes the page, returning its items.
+    pub fn into_items(self) -> Vec<T> {
+        self.items
+    }
+}
+
+/// Iterator over the pages of the active tasks, fetching each page only when it is reached.
+///
+/// Each item is the tasks of one page, or the error that stopped the iteration. Stopping early
+/// leaves the remaining pages unfetched.
+pub struct TaskPages<'a> {
+    /// Client fetching the pages
+    client: &'a TodoistClient,
+    /// Query narrowing down the tasks, if any
+    query: Option<TaskQuery>,
+    /// Maximum number of tasks per page
+    page_size: u32,
+    /// Cursor of the following page, or `None` for the first page
+    cursor: Option<String>,
+    /// Whether the last page was fetched or a request failed
+    done: bool
+}
+
+impl<'a> TaskPages<'a> {
+    /// Creates a new iterator starting at the first page.
+    pub(crate) fn new(client: &'a TodoistClient, query: Option<TaskQuery>, page_size: u32)
+            -> TaskPages<'a> {
+        TaskPages { client, query, page_size, cursor: None, done: false }
+    }
+}
+
+impl<'a> Iterator for TaskPages<'a> {
+    type Item = Result<Vec<Task>, TodoistError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let page = self.client.get_task_page(self.query.as_ref(), self.cursor.as_deref(),
+                                             Some(self.page_size));
+
+        match page {
+            Ok(page) => {
+                self.cursor = following_cursor(self.cursor.as_deref(), page.next_cursor());
+                self.done = self.cursor.is_none();
+                Some(Ok(page.into_items()))
+            },
+            Err(error) => {
+                self.done = true;
+                Some(Err(error))
+            }
+        }
+    }
+}
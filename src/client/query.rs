@@ -0,0 +1,212 @@
+//! # Query
+//!
+//! Module containing structures describing the query parameters of client requests.
+
+use error::TodoistError;
+use model::{TaskId, ProjectId, LabelId};
+use model::comment::CommentTarget;
+
+/// Query parameters narrowing down the tasks returned by the client.
+#[derive(Debug, Clone, Default)]
+pub struct TaskQuery {
+    /// Only return tasks of the project with this identifier
+    project_id: Option<ProjectId>,
+    /// Only return tasks with the label with this identifier
+    label_id: Option<LabelId>,
+    /// Only return tasks matching this Todoist filter (i.e. "today | overdue")
+    filter: Option<String>,
+    /// Language of the filter (i.e. "en")
+    lang: Option<String>,
+    /// Only return tasks with these identifiers
+    ids: Option<Vec<TaskId>>
+}
+
+impl TaskQuery {
+    /// Creates a new query matching all active tasks.
+    pub fn new() -> TaskQuery {
+        TaskQuery::default()
+    }
+
+    /// Only return tasks of the project with this identifier.
+    pub fn set_project_id(&mut self, project_id: Option<ProjectId>) {
+        self.project_id = project_id;
+    }
+
+    /// Only return tasks with the label with this identifier.
+    pub fn set_label_id(&mut self, label_id: Option<LabelId>) {
+        self.label_id = label_id;
+    }
+
+    /// Only return tasks matching a Todoist filter such as "p1" or "#Work".
+    pub fn set_filter(&mut self, filter: Option<&str>) {
+        self.filter = filter.map(String::from);
+    }
+
+    /// Sets the language the filter is written in (i.e. "en").
+    pub fn set_lang(&mut self, lang: Option<&str>) {
+        self.lang = lang.map(String::from);
+    }
+
+    /// Only return tasks with these identifiers.
+    pub fn set_ids(&mut self, ids: Option<Vec<TaskId>>) {
+        self.ids = ids;
+    }
+
+    /// Gets the identifier of the project to return tasks of.
+    pub fn project_id(&self) -> &Option<ProjectId> {
+        &self.project_id
+    }
+
+    /// Gets the identifier of the label to return tasks with.
+    pub fn label_id(&self) -> &Option<LabelId> {
+        &self.label_id
+    }
+
+    /// Gets the Todoist filter the tasks must match.
+    pub fn filter(&self) -> &Option<String> {
+        &self.filter
+    }
+
+    /// Gets the language the filter is written in.
+    pub fn lang(&self) -> &Option<String> {
+        &self.lang
+    }
+
+    /// Gets the identifiers of the tasks to return.
+    pub fn ids(&self) -> &Option<Vec<TaskId>> {
+        &self.ids
+    }
+
+    /// Gets the query parameters to send for the set fields.
+    ///
+    /// Task identifiers are sent as a single comma separated list.
+    pub fn query_pairs(&self) -> Vec<(&'static str, String)> {
+        let mut pairs = vec![];
+
+        if let Some(project_id) = self.project_id {
+            pairs.push(("project_id", project_id.to_string()));
+        }
+
+        if let Some(label_id) = self.label_id {
+            pairs.push(("label_id", label_id.to_string()));
+        }
+
+        if let Some(ref filter) = self.filter {
+            pairs.push(("filter", filter.clone()));
+        }
+
+        if let Some(ref lang) = self.lang {
+            pairs.push(("lang", lang.clone()));
+        }
+
+        if let Some(ref ids) = self.ids {
+            let ids: Vec<String> = ids.iter().map(|id| id.to_string()).collect();
+            pairs.push(("ids", ids.join(",")));
+        }
+
+        pairs
+    }
+}
+
+/// Query parameters selecting the comments of exactly one task or project.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommentFilter {
+    /// The task or project to get the comments of
+    target: CommentTarget
+}
+
+impl CommentFilter {
+    /// Creates a new filter from a task identifier or a project identifier.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TodoistError::InvalidArgument` unless exactly one of the identifiers is set.
+    pub fn new(task_id: Option<TaskId>, project_id: Option<ProjectId>)
+            -> Result<CommentFilter, TodoistError> {
+        match (task_id, project_id) {
+            (Some(task_id), None) => Ok(CommentFilter::for_task(task_id)),
+            (None, Some(project_id)) => Ok(CommentFilter::for_project(project_id)),
+            _ => Err(TodoistError::InvalidArgument(
+                String::from("a comment filter needs exactly one of task_id or project_id")))
+        }
+    }
+
+    /// Creates a new filter selecting the comments of a task.
+    pub fn for_task(task_id: TaskId) -> CommentFilter {
+        CommentFilter { target: CommentTarget::Task(task_id) }
+    }
+
+    /// Creates a new filter selecting the comments of a project.
+    pub fn for_project(project_id: ProjectId) -> CommentFilter {
+        CommentFilter { target: CommentTarget::Project(project_id) }
+    }
+
+    /// Gets the identifier of the task to get the comments of.
+    pub fn task_id(&self) -> Option<TaskId> {
+        self.target.task_id()
+    }
+
+    /// Gets the identifier of the project to get the comments of.
+    pub fn project_id(&self) -> Option<ProjectId> {
+        self.target.project_id()
+    }
+
+    /// Gets the query parameters to send for the filter.
+    pub fn query_pairs(&self) -> Vec<(&'static str, String)> {
+        match self.target {
+            CommentTarget::Task(task_id) => vec![("task_id", task_id.to_string())],
+            CommentTarget::Project(project_id) => vec![("project_id", project_id.to_string())]
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::Url;
+    use client::query::{TaskQuery, CommentFilter};
+
+    #[test]
+    fn serialize_empty_task_query() {
+        assert!(TaskQuery::new().query_pairs().is_empty());
+    }
+
+    #[test]
+    fn serialize_task_query() {
+        let mut query = TaskQuery::new();
+        query.set_project_id(Some(2345));
+        query.set_label_id(Some(124));
+        query.set_filter(Some("#Work & p1"));
+        query.set_lang(Some("en"));
+        query.set_ids(Some(vec![1, 2, 3]));
+
+        let url = Url::parse_with_params("https://example.com/tasks", &query.query_pairs()).unwrap();
+        println!("{}", url);
+        assert_eq!(url.query(),
+                   Some("project_id=2345&label_id=124&filter=%23Work+%26+p1&lang=en&ids=1%2C2%2C3"));
+    }
+
+    #[test]
+    fn create_comment_filter() {
+        let filter = CommentFilter::new(Some(1234), None).unwrap();
+        assert_eq!(filter, CommentFilter::for_task(1234));
+        assert_eq!(filter.task_id(), Some(1234));
+        assert_eq!(filter.project_id(), None);
+
+        let filter = CommentFilter::new(None, Some(2345)).unwrap();
+        assert_eq!(filter, CommentFilter::for_project(2345));
+
+        assert!(CommentFilter::new(None, None).is_err());
+        assert!(CommentFilter::new(Some(1234), Some(2345)).is_err());
+    }
+
+    #[test]
+    fn serialize_comment_filter() {
+        let url = Url::parse_with_params("https://example.com/comments",
+                                         &CommentFilter::for_task(1234).query_pairs()).unwrap();
+        assert_eq!(url.query(), Some("task_id=1234"));
+
+        let url = Url::parse_with_params("https://example.com/comments",
+                                         &CommentFilter::for_project(2345).query_pairs()).unwrap();
+        assert_eq!(url.query(), Some("project_id=2345"));
+    }
+}
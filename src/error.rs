@@ -0,0 +1,242 @@
+//! # Error
+//!
+//! Module containing the error type returned by the Todoist client.
+
+use std::error::Error;
+use std::fmt;
+use reqwest;
+use serde_json;
+
+/// Error returned when a request to the Todoist API fails.
+#[derive(Debug)]
+pub enum TodoistError {
+    /// The request could not be sent or the response could not be read
+    Http(reqwest::Error),
+    /// The response body could not be deserialized, or the request body could not be serialized
+    Deserialize(serde_json::Error),
+    /// The requested resource does not exist
+    NotFound,
+    /// No API token was found in the environment
+    MissingToken,
+    /// The request could not be built from the given arguments
+    InvalidArgument(String),
+    /// The API rate limit was hit and the retry policy allowed no further attempts
+    RateLimited {
+        /// Number of seconds the API asked to wait before retrying, if it said
+        retry_after: Option<u64>
+    },
+    /// The API responded with an unsuccessful status code
+    Api {
+        /// HTTP status code of the response
+        status: u16,
+        /// Body of the response describing the error
+        message: String
+    }
+}
+
+impl TodoistError {
+    /// Gets a message describing the error that is suitable to show to end users, with a hint on
+    /// how to resolve it where there is one.
+    ///
+    /// The `Display` implementation gives a shorter, technical description instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::error::TodoistError;
+    ///
+    /// let error = TodoistError::RateLimited { retry_after: Some(30) };
+    /// assert_eq!(error.user_message(),
+    ///            "Todoist received too many requests. Please wait 30 seconds and try again.");
+    /// ```
+    pub fn user_message(&self) -> String {
+        match *self {
+            TodoistError::Http(ref error) => String::from(network_message(error)),
+            TodoistError::Deserialize(_) => {
+                String::from("Todoist sent a response that could not be understood. Please try \
+                              again later.")
+            },
+            TodoistError::NotFound => {
+                String::from("The item could not be found in Todoist. It may have been deleted.")
+            },
+            TodoistError::MissingToken => {
+                String::from("No Todoist API token was found. Set the TODOIST_API_TOKEN \
+                              environment variable to the token found under Settings → \
+                              Integrations.")
+            },
+            TodoistError::InvalidArgument(ref message) => {
+                format!("The request could not be sent: {}.", message)
+            },
+            TodoistError::RateLimited { retry_after: Some(seconds) } => {
+                format!("Todoist received too many requests. Please wait {} seconds and try again.",
+                        seconds)
+            },
+            TodoistError::RateLimited { retry_after: None } => {
+                String::from("Todoist received too many requests. Please wait a minute and try \
+                              again.")
+            },
+            TodoistError::Api { status, .. } => api_message(status)
+        }
+    }
+}
+
+/// Gets the user facing message for an unsuccessful API status code.
+fn api_message(status: u16) -> String {
+    match status {
+        400 => String::from("Todoist rejected the request as invalid."),
+        401 => {
+            String::from("Your Todoist API token was rejected — generate a new one under \
+                          Settings → Integrations.")
+        },
+        403 => {
+            String::from("You do not have permission to do this. Changes to shared projects \
+                          require the project owner to grant you access.")
+        },
+        500..=599 => {
+            String::from("Todoist is having problems right now. Please try again later.")
+        },
+        _ => format!("Todoist could not complete the request (status {}).", status)
+    }
+}
+
+/// Gets the user facing message for a request that failed before a response was received.
+fn network_message(error: &reqwest::Error) -> &'static str {
+    if error.is_timeout() {
+        return "Todoist took too long to respond. Check your internet connection and try again.";
+    }
+
+    let mut source = error.source();
+    let mut description = String::new();
+
+    while let Some(error) = source {
+        description.push_str(&error.to_string().to_lowercase());
+        source = error.source();
+    }
+
+    if description.contains("dns") || description.contains("resolve") ||
+        description.contains("lookup") {
+        "Todoist could not be found. Check your internet connection and DNS settings."
+    } else if description.contains("certificate") || description.contains("tls") ||
+        description.contains("ssl") {
+        "A secure connection to Todoist could not be established. Check your system clock and \
+         any proxy intercepting HTTPS traffic."
+    } else if error.is_connect() {
+        "Todoist could not be reached. Check your internet connection and try again."
+    } else {
+        "The connection to Todoist failed. Please try again."
+    }
+}
+
+impl fmt::Display for TodoistError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TodoistError::Http(ref error) => write!(f, "HTTP error: {}", error),
+            TodoistError::Deserialize(ref error) => write!(f, "JSON error: {}", error),
+            TodoistError::NotFound => write!(f, "resource not found"),
+            TodoistError::MissingToken => write!(f, "TODOIST_API_TOKEN is not set"),
+            TodoistError::InvalidArgument(ref message) => write!(f, "invalid argument: {}", message),
+            TodoistError::RateLimited { retry_after: Some(seconds) } => {
+                write!(f, "rate limited, retry after {} seconds", seconds)
+            },
+            TodoistError::RateLimited { retry_after: None } => write!(f, "rate limited"),
+            TodoistError::Api { status, ref message } => {
+                write!(f, "API error ({}): {}", status, message)
+            }
+        }
+    }
+}
+
+impl Error for TodoistError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            TodoistError::Http(ref error) => Some(error),
+            TodoistError::Deserialize(ref error) => Some(error),
+            TodoistError::NotFound |
+            TodoistError::MissingToken |
+            TodoistError::InvalidArgument(_) |
+            TodoistError::RateLimited { .. } |
+            TodoistError::Api { .. } => None
+        }
+    }
+}
+
+impl From<reqwest::Error> for TodoistError {
+    fn from(error: reqwest::Error) -> TodoistError {
+        TodoistError::Http(error)
+    }
+}
+
+impl From<serde_json::Error> for TodoistError {
+    fn from(error: serde_json::Error) -> TodoistError {
+        TodoistError::Deserialize(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest;
+    use serde_json;
+    use error::TodoistError;
+
+    #[test]
+    fn user_messages() {
+        let cases = vec![
+            (TodoistError::Api { status: 401, message: String::from("Unauthorized") },
+             "Your Todoist API token was rejected — generate a new one under Settings → \
+              Integrations."),
+            (TodoistError::Api { status: 403, message: String::from("Forbidden") },
+             "You do not have permission to do this. Changes to shared projects require the \
+              project owner to grant you access."),
+            (TodoistError::Api { status: 400, message: String::from("Bad Request") },
+             "Todoist rejected the request as invalid."),
+            (TodoistError::Api { status: 503, message: String::from("Service Unavailable") },
+             "Todoist is having problems right now. Please try again later."),
+            (TodoistError::Api { status: 418, message: String::from("I'm a teapot") },
+             "Todoist could not complete the request (status 418)."),
+            (TodoistError::RateLimited { retry_after: Some(30) },
+             "Todoist received too many requests. Please wait 30 seconds and try again."),
+            (TodoistError::RateLimited { retry_after: None },
+             "Todoist received too many requests. Please wait a minute and try again."),
+            (TodoistError::NotFound,
+             "The item could not be found in Todoist. It may have been deleted."),
+            (TodoistError::MissingToken,
+             "No Todoist API token was found. Set the TODOIST_API_TOKEN environment variable to \
+              the token found under Settings → Integrations.")
+        ];
+
+        for (error, message) in cases {
+            assert_eq!(error.user_message(), message);
+        }
+    }
+
+    #[test]
+    fn user_message_for_refused_connection() {
+        let error = TodoistError::from(reqwest::blocking::get("http://127.0.0.1:1").unwrap_err());
+        assert_eq!(error.user_message(),
+                   "Todoist could not be reached. Check your internet connection and try again.");
+    }
+
+    #[test]
+    fn display_variants() {
+        let http = reqwest::blocking::get("http://127.0.0.1:1").unwrap_err();
+        let http_display = format!("HTTP error: {}", http);
+        let json = serde_json::from_str::<u32>("x").unwrap_err();
+        let json_display = format!("JSON error: {}", json);
+
+        let cases = vec![
+            (TodoistError::from(http), http_display.as_str()),
+            (TodoistError::from(json), json_display.as_str()),
+            (TodoistError::NotFound, "resource not found"),
+            (TodoistError::MissingToken, "TODOIST_API_TOKEN is not set"),
+            (TodoistError::InvalidArgument(String::from("missing id")), "invalid argument: missing id"),
+            (TodoistError::RateLimited { retry_after: Some(30) }, "rate limited, retry after 30 seconds"),
+            (TodoistError::RateLimited { retry_after: None }, "rate limited"),
+            (TodoistError::Api { status: 401, message: String::from("Unauthorized") },
+             "API error (401): Unauthorized")
+        ];
+
+        for (error, display) in cases {
+            assert_eq!(error.to_string(), display);
+        }
+    }
+}
@@ -6,5 +6,13 @@
 extern crate serde_derive;
 extern crate serde;
 extern crate serde_json;
+extern crate reqwest;
+#[cfg(feature = "async")]
+extern crate futures_util;
+#[cfg(feature = "chrono")]
+extern crate chrono;
 
 pub mod model;
+pub mod client;
+pub mod error;
+pub mod parse;
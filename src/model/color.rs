@@ -0,0 +1,429 @@
+//! # Color
+//!
+//! Module containing the color palette shared by projects and labels.
+
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+
+/// A color of the Todoist palette.
+///
+/// The Todoist API represents colors as the integers 30 (berry) to 49 (taupe), which is how this
+/// type is serialized. Values outside of the palette are kept as `Color::Other` when
+/// deserializing, so that they are sent back unchanged.
+///
+/// Colors are compared and hashed by their API value, so a `Color::Other` holding a value of the
+/// palette, like `Color::Other(41)`, is equal to the named color, here `Color::Blue`.
+/// `Color::from_id` and deserialization only produce `Color::Other` for values outside of the
+/// palette.
+#[derive(Debug, Clone, Copy)]
+pub enum Color {
+    /// Berry red (30)
+    Berry,
+    /// Red (31)
+    Red,
+    /// Orange (32)
+    Orange,
+    /// Yellow (33)
+    Yellow,
+    /// Olive green (34)
+    OliveGreen,
+    /// Lime green (35)
+    LimeGreen,
+    /// Green (36)
+    Green,
+    /// Mint green (37)
+    MintGreen,
+    /// Teal (38)
+    Teal,
+    /// Sky blue (39)
+    SkyBlue,
+    /// Light blue (40)
+    LightBlue,
+    /// Blue (41)
+    Blue,
+    /// Grape (42)
+    Grape,
+    /// Violet (43)
+    Violet,
+    /// Lavender (44)
+    Lavender,
+    /// Magenta (45)
+    Magenta,
+    /// Salmon (46)
+    Salmon,
+    /// Charcoal (47)
+    Charcoal,
+    /// Grey (48)
+    Grey,
+    /// Taupe (49)
+    Taupe,
+    /// A value that is not part of the palette known to this crate
+    ///
+    /// Values of the palette are treated as the named color they stand for.
+    Other(u32)
+}
+
+/// All colors of the palette in the order of their API values.
+const COLORS: [Color; 20] = [
+    Color::Berry, Color::Red, Color::Orange, Color::Yellow, Color::OliveGreen, Color::LimeGreen,
+    Color::Green, Color::MintGreen, Color::Teal, Color::SkyBlue, Color::LightBlue, Color::Blue,
+    Color::Grape, Color::Violet, Color::Lavender, Color::Magenta, Color::Salmon, Color::Charcoal,
+    Color::Grey, Color::Taupe
+];
+
+impl Color {
+    /// Converts an API color value, keeping values outside of the palette as `Color::Other`.
+    ///
+    /// Use `Color::try_from` to reject values outside of the palette instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::color::Color;
+    ///
+    /// assert_eq!(Color::from_id(41), Color::Blue);
+    /// assert_eq!(Color::from_id(50), Color::Other(50));
+    /// ```
+    pub fn from_id(id: u32) -> Color {
+        COLORS.iter()
+            .find(|color| u32::from(**color) == id)
+            .cloned()
+            .unwrap_or(Color::Other(id))
+    }
+
+    /// Gets the named color for a `Color::Other` holding a value of the palette, or the color
+    /// itself otherwise.
+    fn normalized(self) -> Color {
+        match self {
+            Color::Other(id) => Color::from_id(id),
+            color => color
+        }
+    }
+
+    /// Finds the color with the given name of the Todoist API, ignoring case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::color::Color;
+    ///
+    /// assert_eq!(Color::from_name("Blue"), Some(Color::Blue));
+    /// assert_eq!(Color::from_name("plaid"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<Color> {
+        COLORS.iter()
+            .find(|color| color.name().is_some_and(|known| known.eq_ignore_ascii_case(name)))
+            .cloned()
+    }
+
+    /// Gets the name of the color used by the Todoist API, i.e. "olive_green".
+    ///
+    /// Returns `None` for values outside of the palette.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::color::Color;
+    ///
+    /// assert_eq!(Color::Blue.name(), Some("blue"));
+    /// assert_eq!(Color::Other(41).name(), Some("blue"));
+    /// assert_eq!(Color::Other(50).name(), None);
+    /// ```
+    pub fn name(&self) -> Option<&'static str> {
+        match self.normalized() {
+            Color::Berry => Some("berry_red"),
+            Color::Red => Some("red"),
+            Color::Orange => Some("orange"),
+            Color::Yellow => Some("yellow"),
+            Color::OliveGreen => Some("olive_green"),
+            Color::LimeGreen => Some("lime_green"),
+            Color::Green => Some("green"),
+            Color::MintGreen => Some("mint_green"),
+            Color::Teal => Some("teal"),
+            Color::SkyBlue => Some("sky_blue"),
+            Color::LightBlue => Some("light_blue"),
+            Color::Blue => Some("blue"),
+            Color::Grape => Some("grape"),
+            Color::Violet => Some("violet"),
+            Color::Lavender => Some("lavender"),
+            Color::Magenta => Some("magenta"),
+            Color::Salmon => Some("salmon"),
+            Color::Charcoal => Some("charcoal"),
+            Color::Grey => Some("grey"),
+            Color::Taupe => Some("taupe"),
+            Color::Other(_) => None
+        }
+    }
+
+    /// Gets the hexadecimal RGB value the Todoist apps display the color with, i.e. "#4073ff".
+    ///
+    /// Returns `None` for values outside of the palette.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::color::Color;
+    ///
+    /// assert_eq!(Color::Blue.hex(), Some("#4073ff"));
+    /// assert_eq!(Color::Other(50).hex(), None);
+    /// ```
+    pub fn hex(&self) -> Option<&'static str> {
+        match self.normalized() {
+            Color::Berry => Some("#b8256f"),
+            Color::Red => Some("#db4035"),
+            Color::Orange => Some("#ff9933"),
+            Color::Yellow => Some("#fad000"),
+            Color::OliveGreen => Some("#afb83b"),
+            Color::LimeGreen => Some("#7ecc49"),
+            Color::Green => Some("#299438"),
+            Color::MintGreen => Some("#6accbc"),
+            Color::Teal => Some("#158fad"),
+            Color::SkyBlue => Some("#14aaf5"),
+            Color::LightBlue => Some("#96c3eb"),
+            Color::Blue => Some("#4073ff"),
+            Color::Grape => Some("#884dff"),
+            Color::Violet => Some("#af38eb"),
+            Color::Lavender => Some("#eb96eb"),
+            Color::Magenta => Some("#e05194"),
+            Color::Salmon => Some("#ff8d85"),
+            Color::Charcoal => Some("#808080"),
+            Color::Grey => Some("#b8b8b8"),
+            Color::Taupe => Some("#ccac93"),
+            Color::Other(_) => None
+        }
+    }
+}
+
+impl TryFrom<u32> for Color {
+    type Error = ColorError;
+
+    /// Converts an API color value, failing for values outside of the range 30 to 49.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use todoist_rest::model::color::Color;
+    ///
+    /// assert_eq!(Color::try_from(38).unwrap(), Color::Teal);
+    /// assert_eq!(Color::try_from(12).unwrap_err().value(), 12);
+    /// ```
+    fn try_from(value: u32) -> Result<Color, ColorError> {
+        match Color::from_id(value) {
+            Color::Other(value) => Err(ColorError { value }),
+            color => Ok(color)
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Color {
+    type Error = ColorNameError;
+
+    /// Converts a color name of the Todoist API, ignoring case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use todoist_rest::model::color::Color;
+    ///
+    /// assert_eq!(Color::try_from("Red").unwrap(), Color::Red);
+    /// assert_eq!(Color::try_from("sky_blue").unwrap(), Color::SkyBlue);
+    /// assert_eq!(Color::try_from("plaid").unwrap_err().name(), "plaid");
+    /// ```
+    fn try_from(name: &'a str) -> Result<Color, ColorNameError> {
+        Color::from_name(name).ok_or_else(|| ColorNameError { name: String::from(name) })
+    }
+}
+
+impl From<Color> for u32 {
+    /// Converts the color to its API value from 30 (berry) to 49 (taupe), or the value kept by
+    /// `Color::Other`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::color::Color;
+    ///
+    /// assert_eq!(u32::from(Color::Charcoal), 47);
+    /// assert_eq!(u32::from(Color::Other(50)), 50);
+    /// ```
+    fn from(color: Color) -> u32 {
+        match color {
+            Color::Berry => 30,
+            Color::Red => 31,
+            Color::Orange => 32,
+            Color::Yellow => 33,
+            Color::OliveGreen => 34,
+            Color::LimeGreen => 35,
+            Color::Green => 36,
+            Color::MintGreen => 37,
+            Color::Teal => 38,
+            Color::SkyBlue => 39,
+            Color::LightBlue => 40,
+            Color::Blue => 41,
+            Color::Grape => 42,
+            Color::Violet => 43,
+            Color::Lavender => 44,
+            Color::Magenta => 45,
+            Color::Salmon => 46,
+            Color::Charcoal => 47,
+            Color::Grey => 48,
+            Color::Taupe => 49,
+            Color::Other(value) => value
+        }
+    }
+}
+
+impl PartialEq for Color {
+    fn eq(&self, other: &Color) -> bool {
+        u32::from(*self) == u32::from(*other)
+    }
+}
+
+impl Eq for Color {}
+
+impl Hash for Color {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        u32::from(*self).hash(state);
+    }
+}
+
+impl Serialize for Color {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where
+        S: Serializer {
+        serializer.serialize_u32(u32::from(*self))
+    }
+}
+
+impl<'de> Deserialize<'de> for Color {
+    fn deserialize<D>(deserializer: D) -> Result<Color, D::Error> where
+        D: Deserializer<'de> {
+        u32::deserialize(deserializer).map(Color::from_id)
+    }
+}
+
+/// Error returned when a value is not a color of the Todoist palette.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColorError {
+    value: u32
+}
+
+impl ColorError {
+    /// Gets the value that is not a color of the palette.
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+}
+
+impl fmt::Display for ColorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid color {}, expected a value from 30 to 49", self.value)
+    }
+}
+
+impl Error for ColorError {}
+
+/// Error returned when a name is not a color of the Todoist palette.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColorNameError {
+    name: String
+}
+
+impl ColorNameError {
+    /// Gets the name that is not a color of the palette.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl fmt::Display for ColorNameError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid color name \"{}\", expected a name like \"berry_red\" or \"taupe\"",
+               self.name)
+    }
+}
+
+impl Error for ColorNameError {}
+
+#[cfg(test)]
+mod tests {
+    extern crate serde_json;
+    use std::collections::HashSet;
+    use std::convert::TryFrom;
+    use model::color::{Color, COLORS};
+
+    #[test]
+    fn convert_color() {
+        for (index, color) in COLORS.iter().enumerate() {
+            assert_eq!(u32::from(*color), 30 + index as u32);
+            assert_eq!(Color::try_from(30 + index as u32).unwrap(), *color);
+        }
+
+        assert_eq!(Color::try_from(29).unwrap_err().value(), 29);
+        assert_eq!(Color::try_from(50).unwrap_err().value(), 50);
+    }
+
+    #[test]
+    fn serialize_and_deserialize_color() {
+        assert_eq!(serde_json::to_string(&Color::LimeGreen).unwrap(), "35");
+        assert_eq!(serde_json::from_str::<Color>("45").unwrap(), Color::Magenta);
+
+        let color = serde_json::from_str::<Color>("7").unwrap();
+        assert_eq!(color, Color::Other(7));
+        assert_eq!(serde_json::to_string(&color).unwrap(), "7");
+        assert!(serde_json::from_str::<Color>("-1").is_err());
+    }
+
+    #[test]
+    fn compare_other_by_value() {
+        let mut colors = HashSet::new();
+        colors.insert(Color::Blue);
+        assert_eq!(Color::Other(41), Color::Blue);
+        assert!(colors.contains(&Color::Other(41)));
+        assert_ne!(Color::Other(50), Color::Other(51));
+
+        assert_eq!(Color::Other(41).hex(), Color::Blue.hex());
+        assert_eq!(serde_json::to_string(&Color::Other(41)).unwrap(), "41");
+        assert!(matches!(serde_json::from_str::<Color>("41").unwrap(), Color::Blue));
+    }
+
+    #[test]
+    fn convert_color_name() {
+        for color in COLORS.iter() {
+            let name = color.name().unwrap();
+            assert_eq!(Color::try_from(name).unwrap(), *color);
+            assert_eq!(Color::try_from(name.to_uppercase().as_str()).unwrap(), *color);
+            assert_eq!(Color::from_name(name), Some(*color));
+        }
+
+        assert_eq!(Color::try_from("Olive_Green").unwrap(), Color::OliveGreen);
+
+        let error = Color::try_from("olive green").unwrap_err();
+        assert_eq!(error.name(), "olive green");
+        assert!(error.to_string().contains("invalid color name \"olive green\""));
+        assert!(Color::try_from("").is_err());
+    }
+
+    #[test]
+    fn color_names_and_hex_values() {
+        assert_eq!(Color::from_id(30).name(), Some("berry_red"));
+        assert_eq!(Color::from_id(41).name(), Some("blue"));
+        assert_eq!(Color::from_id(41).hex(), Some("#4073ff"));
+        assert_eq!(Color::Taupe.hex(), Some("#ccac93"));
+
+        for color in COLORS.iter() {
+            let hex = color.hex().unwrap();
+            assert_eq!(hex.len(), 7);
+            assert!(hex.starts_with('#'));
+            assert!(hex[1..].bytes().all(|b| b.is_ascii_hexdigit()));
+        }
+
+        assert_eq!(Color::try_from(50).unwrap_err().value(), 50);
+        assert_eq!(Color::from_name("other"), None);
+    }
+}
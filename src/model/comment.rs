@@ -1,3 +1,775 @@
+//! # Comment
+//!
+//! Module containing comment-related structures and utilities.
+
+use std::cmp::Ordering;
+use serde::ser::{Serialize, Serializer, SerializeStruct};
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
+use model::{CommentId, TaskId, ProjectId};
+use model::id::{self, Id};
+
+/// Thumbnail of an attached image, as its URL, width and height in pixels.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Thumbnail(String, u32, u32);
+
+impl Thumbnail {
+    /// Gets the URL where the thumbnail can be downloaded.
+    pub fn url(&self) -> &str {
+        &self.0
+    }
+
+    /// Gets the width of the thumbnail in pixels.
+    pub fn width(&self) -> u32 {
+        self.1
+    }
+
+    /// Gets the height of the thumbnail in pixels.
+    pub fn height(&self) -> u32 {
+        self.2
+    }
+}
+
+/// Data model for a file attached to a comment.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Attachment {
+    /// Name of the attached file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    file_name: Option<String>,
+    /// MIME type of the attached file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    file_type: Option<String>,
+    /// URL where the attached file can be downloaded
+    #[serde(skip_serializing_if = "Option::is_none")]
+    file_url: Option<String>,
+    /// Type of the attached resource (i.e. "file" or "image")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    resource_type: Option<String>,
+    /// URL of the attached image (read-only)
+    #[serde(default, skip_serializing)]
+    image: Option<String>,
+    /// Width of the attached image in pixels (read-only)
+    #[serde(default, skip_serializing)]
+    image_width: Option<u32>,
+    /// Height of the attached image in pixels (read-only)
+    #[serde(default, skip_serializing)]
+    image_height: Option<u32>,
+    /// Large thumbnail of the attached image (read-only)
+    #[serde(default, skip_serializing, rename = "tn_l")]
+    large_thumbnail: Option<Thumbnail>,
+    /// Medium thumbnail of the attached image (read-only)
+    #[serde(default, skip_serializing, rename = "tn_m")]
+    medium_thumbnail: Option<Thumbnail>,
+    /// Small thumbnail of the attached image (read-only)
+    #[serde(default, skip_serializing, rename = "tn_s")]
+    small_thumbnail: Option<Thumbnail>
+}
+
+impl Attachment {
+    /// Creates a new file attachment for a file that has already been uploaded to the given URL.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::comment::Attachment;
+    ///
+    /// let attachment = Attachment::create("https://example.com/plan.pdf");
+    /// assert_eq!(attachment.file_url(), &Some(String::from("https://example.com/plan.pdf")));
+    /// assert_eq!(attachment.resource_type(), &Some(String::from("file")));
+    /// ```
+    pub fn create(file_url: &str) -> Attachment {
+        Attachment {
+            file_name: None,
+            file_type: None,
+            file_url: Some(String::from(file_url)),
+            resource_type: Some(String::from("file")),
+            image: None,
+            image_width: None,
+            image_height: None,
+            large_thumbnail: None,
+            medium_thumbnail: None,
+            small_thumbnail: None
+        }
+    }
+
+    /// Sets the name of the attached file.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::comment::Attachment;
+    ///
+    /// let mut attachment = Attachment::create("https://example.com/plan.pdf");
+    /// attachment.set_file_name("plan.pdf");
+    /// assert_eq!(attachment.file_name(), &Some(String::from("plan.pdf")));
+    /// ```
+    pub fn set_file_name(&mut self, file_name: &str) {
+        self.file_name = Some(String::from(file_name));
+    }
+
+    /// Sets the MIME type of the attached file.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::comment::Attachment;
+    ///
+    /// let mut attachment = Attachment::create("https://example.com/plan.pdf");
+    /// attachment.set_file_type("application/pdf");
+    /// assert_eq!(attachment.file_type(), &Some(String::from("application/pdf")));
+    /// ```
+    pub fn set_file_type(&mut self, file_type: &str) {
+        self.file_type = Some(String::from(file_type));
+    }
+
+    /// Gets the name of the attached file.
+    pub fn file_name(&self) -> &Option<String> {
+        &self.file_name
+    }
+
+    /// Gets the MIME type of the attached file.
+    pub fn file_type(&self) -> &Option<String> {
+        &self.file_type
+    }
+
+    /// Gets the URL where the attached file can be downloaded.
+    pub fn file_url(&self) -> &Option<String> {
+        &self.file_url
+    }
+
+    /// Gets the type of the attached resource.
+    pub fn resource_type(&self) -> &Option<String> {
+        &self.resource_type
+    }
+
+    /// Gets the URL of the attached image, for image resources.
+    pub fn image(&self) -> &Option<String> {
+        &self.image
+    }
+
+    /// Gets the width of the attached image in pixels, for image resources.
+    pub fn image_width(&self) -> &Option<u32> {
+        &self.image_width
+    }
+
+    /// Gets the height of the attached image in pixels, for image resources.
+    pub fn image_height(&self) -> &Option<u32> {
+        &self.image_height
+    }
+
+    /// Gets the large thumbnail of the attached image, for image resources.
+    pub fn large_thumbnail(&self) -> &Option<Thumbnail> {
+        &self.large_thumbnail
+    }
+
+    /// Gets the medium thumbnail of the attached image, for image resources.
+    pub fn medium_thumbnail(&self) -> &Option<Thumbnail> {
+        &self.medium_thumbnail
+    }
+
+    /// Gets the small thumbnail of the attached image, for image resources.
+    pub fn small_thumbnail(&self) -> &Option<Thumbnail> {
+        &self.small_thumbnail
+    }
+}
+
+/// The task or project a comment is posted on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CommentTarget {
+    /// The comment is posted on the task with this identifier
+    Task(TaskId),
+    /// The comment is posted on the project with this identifier
+    Project(ProjectId)
+}
+
+impl CommentTarget {
+    /// Gets the identifier of the task, if the target is a task.
+    pub fn task_id(&self) -> Option<TaskId> {
+        match *self {
+            CommentTarget::Task(task_id) => Some(task_id),
+            CommentTarget::Project(_) => None
+        }
+    }
+
+    /// Gets the identifier of the project, if the target is a project.
+    pub fn project_id(&self) -> Option<ProjectId> {
+        match *self {
+            CommentTarget::Task(_) => None,
+            CommentTarget::Project(project_id) => Some(project_id)
+        }
+    }
+}
+
+/// Data model for a comment on a task or a project.
+#[derive(Deserialize, Debug)]
+#[serde(from = "CommentData")]
 pub struct Comment {
+    /// Comment identifier
+    id: Option<CommentId>,
+    /// The task or project the comment is posted on
+    target: Option<CommentTarget>,
+    /// The comment content
+    content: String,
+    /// Date and time when the comment was posted in RFC3339 format in UTC (read-only)
+    posted: Option<String>,
+    /// File attached to the comment
+    attachment: Option<Attachment>
+}
+
+/// A comment as returned by the server, which may carry either target identifier.
+#[derive(Deserialize)]
+struct CommentData {
+    #[serde(default, deserialize_with = "id::option::deserialize")]
+    id: Option<CommentId>,
+    #[serde(default, deserialize_with = "id::option::deserialize")]
+    task_id: Option<TaskId>,
+    #[serde(default, deserialize_with = "id::option::deserialize")]
+    project_id: Option<ProjectId>,
+    content: String,
+    posted: Option<String>,
+    attachment: Option<Attachment>
+}
+
+impl From<CommentData> for Comment {
+    fn from(data: CommentData) -> Comment {
+        let target = match (data.task_id, data.project_id) {
+            (Some(task_id), _) => Some(CommentTarget::Task(task_id)),
+            (None, Some(project_id)) => Some(CommentTarget::Project(project_id)),
+            (None, None) => None
+        };
+
+        Comment {
+            id: data.id,
+            target,
+            content: data.content,
+            posted: data.posted,
+            attachment: data.attachment
+        }
+    }
+}
+
+impl Comment {
+    /// Creates a new comment to post on a task or a project.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::comment::{Comment, CommentTarget};
+    ///
+    /// let comment = Comment::create(CommentTarget::Task(1234), "Test Comment");
+    /// assert_eq!(comment.task_id(), Some(1234));
+    /// assert_eq!(comment.content(), "Test Comment");
+    /// ```
+    pub fn create(target: CommentTarget, content: &str) -> Comment {
+        Comment {
+            id: None,
+            target: Some(target),
+            content: String::from(content),
+            posted: None,
+            attachment: None
+        }
+    }
+
+    /// Creates a new comment to post on a task.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::comment::{Comment, CommentTarget};
+    ///
+    /// let comment = Comment::for_task(1234, "Test Comment");
+    /// assert_eq!(comment.target(), Some(CommentTarget::Task(1234)));
+    /// assert_eq!(comment.content(), "Test Comment");
+    /// ```
+    pub fn for_task(task_id: TaskId, content: &str) -> Comment {
+        Comment::create(CommentTarget::Task(task_id), content)
+    }
+
+    /// Creates a new comment to post on a project.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::comment::{Comment, CommentTarget};
+    ///
+    /// let comment = Comment::for_project(2345, "Test Comment");
+    /// assert_eq!(comment.target(), Some(CommentTarget::Project(2345)));
+    /// assert_eq!(comment.content(), "Test Comment");
+    /// ```
+    pub fn for_project(project_id: ProjectId, content: &str) -> Comment {
+        Comment::create(CommentTarget::Project(project_id), content)
+    }
+
+    /// Sets the content of the comment.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::comment::Comment;
+    ///
+    /// let mut comment = Comment::for_task(1234, "Test Comment");
+    /// comment.set_content("New Comment Content");
+    /// assert_eq!(comment.content(), "New Comment Content");
+    /// ```
+    pub fn set_content(&mut self, content: &str) {
+        self.content = String::from(content);
+    }
+
+    /// Associates the comment with a task.
+    ///
+    /// Any project associated with the comment is removed, since a comment belongs to either a
+    /// task or a project.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::comment::Comment;
+    ///
+    /// let mut comment = Comment::for_project(2345, "Test Comment");
+    /// comment.set_task_id(1234);
+    /// assert_eq!(comment.task_id(), Some(1234));
+    /// assert_eq!(comment.project_id(), None);
+    /// ```
+    pub fn set_task_id(&mut self, task_id: TaskId) {
+        self.target = Some(CommentTarget::Task(task_id));
+    }
+
+    /// Associates the comment with a project.
+    ///
+    /// Any task associated with the comment is removed, since a comment belongs to either a
+    /// task or a project.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::comment::Comment;
+    ///
+    /// let mut comment = Comment::for_task(1234, "Test Comment");
+    /// comment.set_project_id(2345);
+    /// assert_eq!(comment.project_id(), Some(2345));
+    /// assert_eq!(comment.task_id(), None);
+    /// ```
+    pub fn set_project_id(&mut self, project_id: ProjectId) {
+        self.target = Some(CommentTarget::Project(project_id));
+    }
+
+    /// Sets the file attached to the comment.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::comment::{Comment, Attachment};
+    ///
+    /// let mut comment = Comment::for_task(1234, "Test Comment");
+    /// comment.set_attachment(Some(Attachment::create("https://example.com/plan.pdf")));
+    /// assert!(comment.attachment().is_some());
+    /// ```
+    pub fn set_attachment(&mut self, attachment: Option<Attachment>) {
+        self.attachment = attachment;
+    }
+
+    /// Gets the comment identifier.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::comment::Comment;
+    ///
+    /// let comment = Comment::for_task(1234, "Test Comment");
+    /// assert_eq!(comment.id(), &None);
+    /// ```
+    pub fn id(&self) -> &Option<CommentId> {
+        &self.id
+    }
+
+    /// Gets the task or project the comment is posted on.
+    ///
+    /// Comments created locally always have a target. Only a comment deserialized from a
+    /// response carrying neither identifier has none.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::comment::{Comment, CommentTarget};
+    ///
+    /// let mut comment = Comment::for_task(1234, "Test Comment");
+    /// comment.set_project_id(2345);
+    /// assert_eq!(comment.target(), Some(CommentTarget::Project(2345)));
+    /// ```
+    pub fn target(&self) -> Option<CommentTarget> {
+        self.target
+    }
+
+    /// Gets the identifier of the task the comment is posted on.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::comment::Comment;
+    ///
+    /// let comment = Comment::for_task(1234, "Test Comment");
+    /// assert_eq!(comment.task_id(), Some(1234));
+    /// ```
+    pub fn task_id(&self) -> Option<TaskId> {
+        self.target.and_then(|target| target.task_id())
+    }
+
+    /// Gets the identifier of the project the comment is posted on.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::comment::Comment;
+    ///
+    /// let comment = Comment::for_project(2345, "Test Comment");
+    /// assert_eq!(comment.project_id(), Some(2345));
+    /// ```
+    pub fn project_id(&self) -> Option<ProjectId> {
+        self.target.and_then(|target| target.project_id())
+    }
+
+    /// Gets the comment's content.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::comment::Comment;
+    ///
+    /// let comment = Comment::for_task(1234, "Test Comment");
+    /// assert_eq!(comment.content(), "Test Comment");
+    /// ```
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    /// Gets the date and time when the comment was posted in RFC3339 format in UTC.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::comment::Comment;
+    ///
+    /// let comment = Comment::for_task(1234, "Test Comment");
+    /// assert_eq!(comment.posted(), &None);
+    /// ```
+    pub fn posted(&self) -> &Option<String> {
+        &self.posted
+    }
+
+    /// Gets the date and time when the comment was posted as a `chrono::DateTime` in UTC.
+    ///
+    /// Returns `None` if the comment was not posted yet or the timestamp is not in RFC3339 format.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate chrono;
+    /// # extern crate serde_json;
+    /// # extern crate todoist_rest;
+    /// use chrono::{TimeZone, Utc};
+    /// use todoist_rest::model::comment::Comment;
+    ///
+    /// # fn main() {
+    /// let json = r#"{"content": "Note", "posted": "2016-09-22T07:00:00Z"}"#;
+    /// let comment: Comment = serde_json::from_str(json).unwrap();
+    /// assert_eq!(comment.posted_parsed(), Utc.with_ymd_and_hms(2016, 9, 22, 7, 0, 0).single());
+    /// assert_eq!(Comment::for_task(1234, "Note").posted_parsed(), None);
+    /// # }
+    /// ```
+    #[cfg(feature = "chrono")]
+    pub fn posted_parsed(&self) -> Option<DateTime<Utc>> {
+        self.posted.as_ref()
+            .and_then(|posted| DateTime::parse_from_rfc3339(posted).ok())
+            .map(|posted| posted.with_timezone(&Utc))
+    }
+
+    /// Compares comments by when they were posted, the earliest first.
+    ///
+    /// Comments without a posted timestamp come last. With the `chrono` feature the timestamps
+    /// are parsed, so that malformed ones also come last; without it they are compared as text,
+    /// which is chronological for the UTC timestamps returned by the server.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate serde_json;
+    /// # extern crate todoist_rest;
+    /// use todoist_rest::model::comment::Comment;
+    ///
+    /// # fn main() {
+    /// let json = r#"[
+    ///     {"content": "Second", "posted": "2016-09-22T08:00:00Z"},
+    ///     {"content": "First", "posted": "2016-09-22T07:00:00Z"}
+    /// ]"#;
+    /// let mut comments: Vec<Comment> = serde_json::from_str(json).unwrap();
+    /// comments.sort_by(Comment::cmp_by_posted);
+    /// assert_eq!(comments[0].content(), "First");
+    /// # }
+    /// ```
+    pub fn cmp_by_posted(&self, other: &Comment) -> Ordering {
+        match (self.posted_key(), other.posted_key()) {
+            (Some(ref posted), Some(ref other_posted)) => posted.cmp(other_posted),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal
+        }
+    }
+
+    /// Gets the value comments are sorted by in `cmp_by_posted`.
+    #[cfg(feature = "chrono")]
+    fn posted_key(&self) -> Option<DateTime<Utc>> {
+        self.posted_parsed()
+    }
+
+    /// Gets the value comments are sorted by in `cmp_by_posted`.
+    #[cfg(not(feature = "chrono"))]
+    fn posted_key(&self) -> Option<&str> {
+        self.posted.as_deref()
+    }
+
+    /// Gets the file attached to the comment.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::comment::Comment;
+    ///
+    /// let comment = Comment::for_task(1234, "Test Comment");
+    /// assert!(comment.attachment().is_none());
+    /// ```
+    pub fn attachment(&self) -> &Option<Attachment> {
+        &self.attachment
+    }
+}
+
+impl Serialize for Comment {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where
+        S: Serializer {
+        let mut len = 1;
+
+        if self.target.is_some() {
+            len += 1;
+        }
+
+        if self.attachment.is_some() {
+            len += 1;
+        }
+
+        let mut state = serializer.serialize_struct("Comment", len)?;
+
+        state.serialize_field("content", &self.content)?;
+
+        match self.target {
+            Some(CommentTarget::Task(task_id)) => state.serialize_field("task_id", &Id(task_id))?,
+            Some(CommentTarget::Project(project_id)) =>
+                state.serialize_field("project_id", &Id(project_id))?,
+            None => {}
+        }
+
+        if let Some(ref attachment) = self.attachment {
+            state.serialize_field("attachment", attachment)?;
+        }
+
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate serde_json;
+    use model::comment::{Comment, CommentTarget, Attachment};
+
+    #[test]
+    fn create_and_serialize_comment() {
+        let mut new_comment = Comment::create(CommentTarget::Task(1234), "Test Comment");
+        let json = serde_json::to_string(&new_comment).unwrap();
+        println!("{}", json);
+        assert_eq!(json, r#"{"content":"Test Comment","task_id":1234}"#);
+
+        new_comment.set_project_id(2345);
+        let json = serde_json::to_string(&new_comment).unwrap();
+        println!("{}", json);
+        assert_eq!(json, r#"{"content":"Test Comment","project_id":2345}"#);
+    }
+
+    #[test]
+    fn create_and_serialize_comment_for_target() {
+        let comment = Comment::for_task(1234, "Test Comment");
+        assert_eq!(comment.task_id(), Some(1234));
+        assert_eq!(comment.project_id(), None);
+        let json = serde_json::to_string(&comment).unwrap();
+        println!("{}", json);
+        assert_eq!(json, r#"{"content":"Test Comment","task_id":1234}"#);
+
+        let comment = Comment::for_project(2345, "Test Comment");
+        assert_eq!(comment.task_id(), None);
+        assert_eq!(comment.project_id(), Some(2345));
+        let json = serde_json::to_string(&comment).unwrap();
+        println!("{}", json);
+        assert_eq!(json, r#"{"content":"Test Comment","project_id":2345}"#);
+        assert!(!json.contains("task_id"));
+    }
+
+    #[test]
+    fn deserialize_comment_target() {
+        let comment: Comment = serde_json::from_str(
+            r#"{"content": "Note", "task_id": 1234, "project_id": null}"#).unwrap();
+        assert_eq!(comment.target(), Some(CommentTarget::Task(1234)));
+
+        let comment: Comment = serde_json::from_str(
+            r#"{"content": "Note", "task_id": null, "project_id": 2345}"#).unwrap();
+        assert_eq!(comment.target(), Some(CommentTarget::Project(2345)));
+    }
+
+    #[test]
+    fn create_and_serialize_comment_with_attachment() {
+        let mut attachment = Attachment::create("https://example.com/plan.pdf");
+        attachment.set_file_name("plan.pdf");
+        attachment.set_file_type("application/pdf");
+
+        let mut new_comment = Comment::for_task(1234, "Test Comment");
+        new_comment.set_attachment(Some(attachment));
+        let json = serde_json::to_string(&new_comment).unwrap();
+        println!("{}", json);
+        assert!(json.contains(r#""attachment":{"file_name":"plan.pdf","file_type":"application/pdf","file_url":"https://example.com/plan.pdf","resource_type":"file"}"#));
+    }
+
+    #[test]
+    fn deserialize_comment() {
+        let json = r#"
+            {
+                "content": "Need one bottle of milk",
+                "id": 1234,
+                "posted": "2016-09-22T07:00:00Z",
+                "task_id": 2345
+            }
+        "#;
+
+        let comment: Comment = serde_json::from_str(json).unwrap();
+        assert_eq!(comment.content(), "Need one bottle of milk");
+        assert_eq!(comment.id().unwrap(), 1234);
+        assert_eq!(comment.task_id().unwrap(), 2345);
+        assert_eq!(comment.project_id(), None);
+        assert_eq!(comment.posted(), &Some(String::from("2016-09-22T07:00:00Z")));
+        assert!(comment.attachment().is_none());
+    }
+
+    #[test]
+    fn deserialize_project_comment_with_attachment() {
+        let json = r#"
+            {
+                "content": "Project plan",
+                "id": 1235,
+                "posted": "2016-09-22T07:00:00Z",
+                "project_id": 3456,
+                "attachment": {
+                    "file_name": "plan.pdf",
+                    "file_type": "application/pdf",
+                    "file_url": "https://cdn-domain.tld/path/to/plan.pdf",
+                    "resource_type": "file"
+                }
+            }
+        "#;
+
+        let comment: Comment = serde_json::from_str(json).unwrap();
+        assert_eq!(comment.project_id().unwrap(), 3456);
+        let attachment = comment.attachment().clone().unwrap();
+        assert_eq!(attachment.file_name(), &Some(String::from("plan.pdf")));
+        assert_eq!(attachment.resource_type(), &Some(String::from("file")));
+    }
+
+    #[test]
+    fn deserialize_comment_with_image_attachment() {
+        let json = r#"
+            {
+                "content": "Whiteboard",
+                "id": 1236,
+                "posted": "2016-09-22T07:00:00Z",
+                "task_id": 2995104339,
+                "attachment": {
+                    "file_name": "whiteboard.jpg",
+                    "file_type": "image/jpeg",
+                    "file_url": "https://cdn-domain.tld/path/to/whiteboard.jpg",
+                    "resource_type": "image",
+                    "image": "https://cdn-domain.tld/path/to/whiteboard.jpg",
+                    "image_width": 1600,
+                    "image_height": 1200,
+                    "tn_l": ["https://cdn-domain.tld/path/to/whiteboard_l.jpg", 528, 396],
+                    "tn_m": ["https://cdn-domain.tld/path/to/whiteboard_m.jpg", 400, 300],
+                    "tn_s": ["https://cdn-domain.tld/path/to/whiteboard_s.jpg", 180, 135]
+                }
+            }
+        "#;
+
+        let comment: Comment = serde_json::from_str(json).unwrap();
+        let attachment = comment.attachment().clone().unwrap();
+        assert_eq!(attachment.resource_type(), &Some(String::from("image")));
+        assert_eq!(attachment.image_width(), &Some(1600));
+        assert_eq!(attachment.image_height(), &Some(1200));
+        let thumbnail = attachment.medium_thumbnail().clone().unwrap();
+        assert_eq!(thumbnail.url(), "https://cdn-domain.tld/path/to/whiteboard_m.jpg");
+        assert_eq!((thumbnail.width(), thumbnail.height()), (400, 300));
+        assert_eq!(attachment.small_thumbnail().clone().unwrap().width(), 180);
+
+        let json = serde_json::to_string(&attachment).unwrap();
+        println!("{}", json);
+        assert!(!json.contains("tn_l"));
+        assert!(!json.contains("image_width"));
+    }
+
+    #[test]
+    fn deserialize_sparse_comment() {
+        let comment: Comment = serde_json::from_str(r#"{"content": "Need one bottle of milk"}"#)
+            .unwrap();
+        assert_eq!(comment.content(), "Need one bottle of milk");
+        assert_eq!(comment.id(), &None);
+        assert_eq!(comment.target(), None);
+        assert_eq!(comment.posted(), &None);
+        assert!(comment.attachment().is_none());
+
+        let comment: Comment = serde_json::from_str(r#"{"content": "Plan", "attachment": {}}"#)
+            .unwrap();
+        assert_eq!(comment.attachment().clone().unwrap().file_url(), &None);
+    }
+
+    #[test]
+    fn sort_comments_by_posted() {
+        let json = r#"
+            [
+                {"content": "Third", "posted": "2016-09-24T07:00:00Z"},
+                {"content": "Unposted"},
+                {"content": "First", "posted": "2016-09-22T07:00:00Z"},
+                {"content": "Second", "posted": "2016-09-23T18:30:00Z"}
+            ]
+        "#;
+
+        let mut comments: Vec<Comment> = serde_json::from_str(json).unwrap();
+        comments.sort_by(Comment::cmp_by_posted);
+        let contents: Vec<&str> = comments.iter().map(|comment| comment.content()).collect();
+        assert_eq!(contents, vec!["First", "Second", "Third", "Unposted"]);
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn parse_posted() {
+        use chrono::{TimeZone, Utc};
+
+        let json = r#"
+            [
+                {"content": "Offset", "posted": "2016-09-22T09:00:00+02:00"},
+                {"content": "Malformed", "posted": "yesterday"},
+                {"content": "Fraction", "posted": "2016-09-22T06:59:59.500Z"}
+            ]
+        "#;
+
+        let mut comments: Vec<Comment> = serde_json::from_str(json).unwrap();
+        assert_eq!(comments[0].posted_parsed(),
+                   Utc.with_ymd_and_hms(2016, 9, 22, 7, 0, 0).single());
+        assert_eq!(comments[1].posted_parsed(), None);
+        assert_eq!(comments[1].posted(), &Some(String::from("yesterday")));
 
-}
\ No newline at end of file
+        comments.sort_by(Comment::cmp_by_posted);
+        let contents: Vec<&str> = comments.iter().map(|comment| comment.content()).collect();
+        assert_eq!(contents, vec!["Fraction", "Offset", "Malformed"]);
+    }
+}
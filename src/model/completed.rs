@@ -0,0 +1,176 @@
+//! # Completed
+//!
+//! Module containing structures for tasks that were completed.
+
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
+use model::{TaskId, ProjectId};
+use model::id;
+use model::task::Task;
+
+/// Data model for an entry in the list of completed tasks.
+///
+/// Completed tasks are returned with a different shape than active tasks, holding only what is
+/// needed to show what got done and when.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CompletedTask {
+    /// Identifier of the task that was completed
+    #[serde(deserialize_with = "id::deserialize")]
+    task_id: TaskId,
+    /// The task content at the time it was completed
+    content: String,
+    /// Date and time when the task was completed in RFC3339 format in UTC
+    #[serde(alias = "completed_at")]
+    completed_date: String,
+    /// Identifier of the project the task belongs to
+    #[serde(deserialize_with = "id::deserialize")]
+    project_id: ProjectId,
+    /// Additional data stored with the entry, such as the attachments of recurring tasks
+    #[serde(default)]
+    meta_data: Option<String>
+}
+
+impl CompletedTask {
+    /// Gets the identifier of the task that was completed.
+    pub fn task_id(&self) -> TaskId {
+        self.task_id
+    }
+
+    /// Gets the task content at the time it was completed.
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    /// Gets the date and time when the task was completed in RFC3339 format in UTC.
+    pub fn completed_date(&self) -> &str {
+        &self.completed_date
+    }
+
+    /// Gets the date and time when the task was completed as a `chrono::DateTime` in UTC.
+    ///
+    /// Returns `None` if the timestamp is not in RFC3339 format.
+    #[cfg(feature = "chrono")]
+    pub fn completed_date_parsed(&self) -> Option<DateTime<Utc>> {
+        DateTime::parse_from_rfc3339(&self.completed_date).ok()
+            .map(|completed_date| completed_date.with_timezone(&Utc))
+    }
+
+    /// Gets the identifier of the project the task belongs to.
+    pub fn project_id(&self) -> ProjectId {
+        self.project_id
+    }
+
+    /// Gets the additional data stored with the entry.
+    pub fn meta_data(&self) -> &Option<String> {
+        &self.meta_data
+    }
+
+    /// Gets the original task marked as completed, with the content and project it had when it
+    /// was completed.
+    ///
+    /// The original task is the one with the identifier of the entry, i.e. one fetched or cached
+    /// before it was completed. The content and project of the entry take precedence over those
+    /// of the original task, which may be outdated; the other fields are kept as they are.
+    ///
+    /// Returns `None` if the original task does not have the identifier of the entry.
+    pub fn to_task(&self, original: &Task) -> Option<Task> {
+        if original.id() != &Some(self.task_id) {
+            return None;
+        }
+
+        let mut task = original.clone();
+        task.set_completed(true);
+        task.set_content(&self.content);
+        task.set_project_id(Some(self.project_id));
+        Some(task)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate serde_json;
+    use model::completed::CompletedTask;
+    use model::task::{Task, Priority};
+
+    #[test]
+    fn deserialize_completed_tasks() {
+        let json = r#"
+            [
+                {
+                    "id": 1899066186,
+                    "task_id": 2995104339,
+                    "user_id": 2671355,
+                    "content": "Buy Milk",
+                    "completed_date": "2015-02-17T15:40:41Z",
+                    "project_id": 2203306141,
+                    "section_id": 7025,
+                    "note_count": 0,
+                    "meta_data": null
+                },
+                {
+                    "task_id": 2995104340,
+                    "content": "Water the plants",
+                    "completed_at": "2015-02-18T08:00:00.000000Z",
+                    "project_id": 2203306141,
+                    "meta_data": "{\"recurring\": true}"
+                }
+            ]
+        "#;
+
+        let completed: Vec<CompletedTask> = serde_json::from_str(json).unwrap();
+        assert_eq!(completed[0].task_id(), 2995104339);
+        assert_eq!(completed[0].content(), "Buy Milk");
+        assert_eq!(completed[0].completed_date(), "2015-02-17T15:40:41Z");
+        assert_eq!(completed[0].project_id(), 2203306141);
+        assert_eq!(completed[0].meta_data(), &None);
+        assert_eq!(completed[1].completed_date(), "2015-02-18T08:00:00.000000Z");
+        assert_eq!(completed[1].meta_data(), &Some(String::from("{\"recurring\": true}")));
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn parse_completed_date() {
+        use chrono::{TimeZone, Utc};
+
+        let json = r#"{"task_id": 1, "content": "Buy Milk", "project_id": 2,
+                       "completed_date": "2015-02-17T15:40:41Z"}"#;
+        let completed: CompletedTask = serde_json::from_str(json).unwrap();
+        assert_eq!(completed.completed_date_parsed(),
+                   Utc.with_ymd_and_hms(2015, 2, 17, 15, 40, 41).single());
+
+        let json = r#"{"task_id": 1, "content": "Buy Milk", "completed_date": "last week",
+                       "project_id": 2}"#;
+        let completed: CompletedTask = serde_json::from_str(json).unwrap();
+        assert_eq!(completed.completed_date_parsed(), None);
+    }
+
+    #[test]
+    fn convert_completed_task() {
+        let original: Task = serde_json::from_str(r#"{"id": 2995104339, "content": "Buy milk",
+                                                      "project_id": 2203306140, "priority": 4}"#)
+            .unwrap();
+        let json = r#"{"task_id": 2995104339, "content": "Buy Milk",
+                       "completed_date": "2015-02-17T15:40:41Z", "project_id": 2203306141}"#;
+        let completed: CompletedTask = serde_json::from_str(json).unwrap();
+
+        let task = completed.to_task(&original).unwrap();
+        assert!(task.completed());
+        assert_eq!(task.id(), &Some(2995104339));
+        assert_eq!(task.content(), "Buy Milk");
+        assert_eq!(task.project_id(), &Some(2203306141));
+        assert_eq!(task.priority(), Priority::Urgent);
+        assert!(!original.completed());
+    }
+
+    #[test]
+    fn convert_completed_task_with_other_original() {
+        let json = r#"{"task_id": 2995104339, "content": "Buy Milk",
+                       "completed_date": "2015-02-17T15:40:41Z", "project_id": 2203306141}"#;
+        let completed: CompletedTask = serde_json::from_str(json).unwrap();
+
+        let other: Task = serde_json::from_str(r#"{"id": 2995104340, "content": "Buy Milk"}"#)
+            .unwrap();
+        assert_eq!(completed.to_task(&other), None);
+        assert_eq!(completed.to_task(&Task::create("Buy Milk")), None);
+    }
+}
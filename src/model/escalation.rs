@@ -0,0 +1,225 @@
+//! # Escalation
+//!
+//! Module containing utilities for raising the priority of overdue tasks.
+
+use std::time::SystemTime;
+use model::task::{Task, Priority};
+
+/// Policy deciding which overdue tasks are escalated and by how much.
+///
+/// The default policy escalates every task below urgent priority by one level for each started
+/// period of 3 days it is overdue, up to urgent priority.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EscalationPolicy {
+    /// Tasks with this priority or higher are not escalated
+    threshold: Priority,
+    /// Number of days overdue for each priority level raised
+    days_per_level: u32,
+    /// The highest priority a task is escalated to
+    cap: Priority
+}
+
+impl EscalationPolicy {
+    /// Creates the default policy.
+    pub fn new() -> EscalationPolicy {
+        EscalationPolicy::default()
+    }
+
+    /// Sets the priority from which tasks are no longer escalated.
+    pub fn set_threshold(&mut self, threshold: Priority) {
+        self.threshold = threshold;
+    }
+
+    /// Sets the number of days overdue for each priority level raised. Zero is treated as one.
+    pub fn set_days_per_level(&mut self, days_per_level: u32) {
+        self.days_per_level = days_per_level.max(1);
+    }
+
+    /// Sets the highest priority a task is escalated to.
+    pub fn set_cap(&mut self, cap: Priority) {
+        self.cap = cap;
+    }
+
+    /// Gets the priority from which tasks are no longer escalated.
+    pub fn threshold(&self) -> Priority {
+        self.threshold
+    }
+
+    /// Gets the number of days overdue for each priority level raised.
+    pub fn days_per_level(&self) -> u32 {
+        self.days_per_level
+    }
+
+    /// Gets the highest priority a task is escalated to.
+    pub fn cap(&self) -> Priority {
+        self.cap
+    }
+
+    /// Gets the priority an overdue task is escalated to, which may be its current priority.
+    fn escalated(&self, priority: Priority, days_overdue: u32) -> Priority {
+        let levels = days_overdue.div_ceil(self.days_per_level);
+        let escalated = Priority::saturating_from(u32::from(priority).saturating_add(levels));
+        escalated.min(self.cap).max(priority)
+    }
+}
+
+impl Default for EscalationPolicy {
+    fn default() -> EscalationPolicy {
+        EscalationPolicy {
+            threshold: Priority::Urgent,
+            days_per_level: 3,
+            cap: Priority::Urgent
+        }
+    }
+}
+
+/// A suggested priority raise for an overdue task.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Escalation {
+    /// The task with its raised priority as its only change
+    task: Task,
+    /// The priority of the task before the escalation
+    previous_priority: Priority
+}
+
+impl Escalation {
+    /// Gets the task with its raised priority.
+    ///
+    /// The priority is recorded as the only change, so the task can be passed as is to
+    /// `TodoistClient::update_task`.
+    pub fn task(&self) -> &Task {
+        &self.task
+    }
+
+    /// Gets the priority of the task before the escalation.
+    pub fn previous_priority(&self) -> Priority {
+        self.previous_priority
+    }
+
+    /// Gets the priority the task is raised to.
+    pub fn priority(&self) -> Priority {
+        self.task.priority()
+    }
+
+    /// Takes the task with its raised priority.
+    pub fn into_task(self) -> Task {
+        self.task
+    }
+}
+
+/// Suggests priority raises for the tasks that are overdue at the given point in time, following
+/// the policy.
+///
+/// Completed tasks, tasks without a due date and tasks at or above the threshold priority are
+/// skipped, as are tasks whose priority would not change. Nothing is sent to the server, so the
+/// suggestions can be previewed before the updates are applied.
+///
+/// # Example
+///
+/// ```
+/// use std::time::{Duration, UNIX_EPOCH};
+/// use todoist_rest::model::escalation::{self, EscalationPolicy};
+/// use todoist_rest::model::task::{Task, Due, Priority};
+///
+/// let mut due = Due::create("Christmas");
+/// due.set_date_validated("2017-12-20").unwrap();
+/// let tasks = vec![Task::create_with_due("Buy presents", due)];
+///
+/// let now = UNIX_EPOCH + Duration::from_secs(1_514_203_200); // 2017-12-25T12:00:00Z
+/// let escalations = escalation::suggest(&tasks, now, &EscalationPolicy::new());
+/// assert_eq!(escalations[0].previous_priority(), Priority::Normal);
+/// assert_eq!(escalations[0].priority(), Priority::High);
+/// assert_eq!(escalations[0].task().update_payload().to_string(), r#"{"priority":3}"#);
+/// ```
+pub fn suggest(tasks: &[Task], now: SystemTime, policy: &EscalationPolicy) -> Vec<Escalation> {
+    tasks.iter()
+        .filter(|task| !task.completed() && task.priority() < policy.threshold)
+        .filter_map(|task| {
+            let days_overdue = task.due()?.days_overdue_at(now)?;
+            let priority = policy.escalated(task.priority(), days_overdue);
+
+            if priority == task.priority() {
+                return None;
+            }
+
+            let mut escalated = task.clone();
+            escalated.reset_changes();
+            escalated.set_priority(priority);
+
+            Some(Escalation { task: escalated, previous_priority: task.priority() })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+    use model::escalation::{EscalationPolicy, suggest};
+    use model::task::{Task, Due, Priority};
+
+    /// Gets 2017-12-25T12:00:00Z, the point in time the fixture tasks are evaluated at.
+    fn christmas() -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(1_514_203_200)
+    }
+
+    /// Creates a task due on the given date with the given priority.
+    fn task_due(content: &str, date: &str, priority: Priority) -> Task {
+        let mut due = Due::create(date);
+        due.set_date_validated(date).unwrap();
+        let mut task = Task::create_with_due(content, due);
+        task.set_priority(priority);
+        task.reset_changes();
+        task
+    }
+
+    #[test]
+    fn suggest_for_overdue_tasks() {
+        let mut completed = task_due("Completed", "2017-12-15", Priority::Normal);
+        completed.set_completed(true);
+
+        let tasks = vec![
+            task_due("Not overdue", "2017-12-25", Priority::Normal),
+            task_due("Two days overdue", "2017-12-23", Priority::Normal),
+            task_due("Ten days overdue", "2017-12-15", Priority::Medium),
+            task_due("Already urgent", "2017-12-15", Priority::Urgent),
+            completed,
+            Task::create("No due date")
+        ];
+
+        let escalations = suggest(&tasks, christmas(), &EscalationPolicy::new());
+        let preview: Vec<_> = escalations.iter()
+            .map(|escalation| {
+                (escalation.task().content(), escalation.previous_priority(), escalation.priority())
+            })
+            .collect();
+        assert_eq!(preview, vec![
+            ("Two days overdue", Priority::Normal, Priority::Medium),
+            ("Ten days overdue", Priority::Medium, Priority::Urgent)
+        ]);
+
+        assert_eq!(escalations[1].task().update_payload().to_string(), r#"{"priority":4}"#);
+        assert_eq!(tasks[2].priority(), Priority::Medium);
+    }
+
+    #[test]
+    fn suggest_with_custom_policy() {
+        let tasks = vec![
+            task_due("Two days overdue", "2017-12-23", Priority::Normal),
+            task_due("Ten days overdue", "2017-12-15", Priority::Normal),
+            task_due("High", "2017-12-15", Priority::High)
+        ];
+
+        let mut policy = EscalationPolicy::new();
+        policy.set_threshold(Priority::High);
+        policy.set_days_per_level(5);
+        policy.set_cap(Priority::High);
+
+        let escalations = suggest(&tasks, christmas(), &policy);
+        assert_eq!(escalations.len(), 2);
+        assert_eq!(escalations[0].priority(), Priority::Medium);
+        assert_eq!(escalations[1].priority(), Priority::High);
+
+        policy.set_cap(Priority::Normal);
+        assert!(suggest(&tasks, christmas(), &policy).is_empty());
+    }
+}
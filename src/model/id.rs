@@ -0,0 +1,192 @@
+//! # Identifiers
+//!
+//! Module containing the JSON representation of identifiers.
+//!
+//! Identifiers are serialized as JSON numbers, except for identifiers above `MAX_SAFE_ID` which
+//! are serialized as strings. Many JSON consumers, like JavaScript and proxies written in it,
+//! read every number as a double, which holds integers exactly only up to 2^53. Larger
+//! identifiers would silently be rounded to a neighbouring value, and refer to another object
+//! once they are sent back. Identifiers are deserialized from both numbers and strings, so the
+//! string form is read back transparently.
+//!
+//! The `serialize` and `deserialize` functions and the `option` and `vec` modules can be used
+//! with `#[serde(with = "...")]` on identifier fields of other types.
+//!
+//! # Example
+//!
+//! ```
+//! extern crate serde_json;
+//! # extern crate todoist_rest;
+//! use todoist_rest::model::task::Task;
+//!
+//! # fn main() {
+//! let task: Task = serde_json::from_str(r#"{
+//!     "content": "Buy milk",
+//!     "project_id": "9007199254740993"
+//! }"#).unwrap();
+//! assert_eq!(task.project_id(), &Some(9_007_199_254_740_993));
+//! assert!(task.to_create_json().unwrap().contains(r#""project_id":"9007199254740993""#));
+//! # }
+//! ```
+
+use std::convert::TryFrom;
+use std::fmt;
+use serde::de::{self, Deserialize, Deserializer, Unexpected, Visitor};
+use serde::ser::{Serialize, Serializer};
+use serde_json::Value;
+
+/// The largest identifier serialized as a JSON number, 2^53 - 1.
+pub const MAX_SAFE_ID: u64 = 9_007_199_254_740_991;
+
+/// An identifier with the JSON representation of this module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Id(pub u64);
+
+impl Serialize for Id {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where
+        S: Serializer {
+        if self.0 > MAX_SAFE_ID {
+            serializer.collect_str(&self.0)
+        } else {
+            serializer.serialize_u64(self.0)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Id {
+    fn deserialize<D>(deserializer: D) -> Result<Id, D::Error> where
+        D: Deserializer<'de> {
+        deserializer.deserialize_any(IdVisitor)
+    }
+}
+
+impl From<Id> for Value {
+    fn from(id: Id) -> Value {
+        if id.0 > MAX_SAFE_ID {
+            Value::from(id.0.to_string())
+        } else {
+            Value::from(id.0)
+        }
+    }
+}
+
+/// Visitor reading an identifier from a number or a string of digits.
+struct IdVisitor;
+
+impl<'de> Visitor<'de> for IdVisitor {
+    type Value = Id;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an identifier as a non-negative integer or a string of digits")
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Id, E> where
+        E: de::Error {
+        Ok(Id(value))
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Id, E> where
+        E: de::Error {
+        u64::try_from(value).map(Id).map_err(|_| E::invalid_value(Unexpected::Signed(value), &self))
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Id, E> where
+        E: de::Error {
+        match value.parse() {
+            Ok(id) if value.bytes().all(|b| b.is_ascii_digit()) => Ok(Id(id)),
+            _ => Err(E::invalid_value(Unexpected::Str(value), &self))
+        }
+    }
+}
+
+/// Serializes an identifier, as a string if it is above `MAX_SAFE_ID`.
+pub fn serialize<S>(id: &u64, serializer: S) -> Result<S::Ok, S::Error> where
+    S: Serializer {
+    Id(*id).serialize(serializer)
+}
+
+/// Deserializes an identifier from a number or a string.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<u64, D::Error> where
+    D: Deserializer<'de> {
+    Id::deserialize(deserializer).map(|id| id.0)
+}
+
+/// Serialization of optional identifiers, with `null` for `None`.
+pub mod option {
+    use serde::de::{Deserialize, Deserializer};
+    use serde::ser::{Serialize, Serializer};
+    use super::Id;
+
+    /// Serializes an optional identifier.
+    pub fn serialize<S>(id: &Option<u64>, serializer: S) -> Result<S::Ok, S::Error> where
+        S: Serializer {
+        id.map(Id).serialize(serializer)
+    }
+
+    /// Deserializes an optional identifier from `null`, a number or a string.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<u64>, D::Error> where
+        D: Deserializer<'de> {
+        Option::<Id>::deserialize(deserializer).map(|id| id.map(|id| id.0))
+    }
+}
+
+/// Serialization of lists of identifiers.
+pub mod vec {
+    use serde::de::{Deserialize, Deserializer};
+    use serde::ser::Serializer;
+    use super::Id;
+
+    /// Serializes a list of identifiers.
+    pub fn serialize<S>(ids: &[u64], serializer: S) -> Result<S::Ok, S::Error> where
+        S: Serializer {
+        serializer.collect_seq(ids.iter().map(|id| Id(*id)))
+    }
+
+    /// Deserializes a list of identifiers given as numbers or strings.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u64>, D::Error> where
+        D: Deserializer<'de> {
+        Vec::<Id>::deserialize(deserializer).map(|ids| ids.into_iter().map(|id| id.0).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate serde_json;
+    use model::id::{self, Id, MAX_SAFE_ID};
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Ids {
+        #[serde(with = "id")]
+        id: u64,
+        #[serde(default, with = "id::option")]
+        parent_id: Option<u64>,
+        #[serde(default, with = "id::vec")]
+        label_ids: Vec<u64>
+    }
+
+    #[test]
+    fn serialize_large_ids_as_strings() {
+        let ids = Ids { id: 9_007_199_254_740_993, parent_id: Some(MAX_SAFE_ID),
+                        label_ids: vec![1, 9_007_199_254_740_993] };
+        let json = serde_json::to_string(&ids).unwrap();
+        assert_eq!(json, r#"{"id":"9007199254740993","parent_id":9007199254740991,"label_ids":[1,"9007199254740993"]}"#);
+
+        let parsed: Ids = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, ids);
+        assert_eq!(serde_json::Value::from(Id(9_007_199_254_740_993)), "9007199254740993");
+    }
+
+    #[test]
+    fn deserialize_ids() {
+        let ids: Ids = serde_json::from_str(r#"{"id": "42", "parent_id": null}"#).unwrap();
+        assert_eq!(ids, Ids { id: 42, parent_id: None, label_ids: vec![] });
+
+        let ids: Ids = serde_json::from_str(r#"{"id": 18446744073709551615}"#).unwrap();
+        assert_eq!(ids.id, u64::MAX);
+
+        for json in &[r#"{"id": -1}"#, r#"{"id": "+42"}"#, r#"{"id": "4x"}"#, r#"{"id": ""}"#,
+                      r#"{"id": 4.5}"#, r#"{"id": "18446744073709551616"}"#] {
+            assert!(serde_json::from_str::<Ids>(json).is_err(), "{} was accepted", json);
+        }
+    }
+}
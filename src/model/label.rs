@@ -1,3 +1,160 @@
+//! # Label
+//!
+//! Module containing label-related structures and utilities.
+
+use model::LabelId;
+use model::id;
+use model::color::Color;
+
+/// Data model for a label that can be associated with tasks.
+///
+/// Serializing a label gives the body for creating or updating it, which leaves out the read-only
+/// fields and an unset color.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
 pub struct Label {
+    /// Label identifier (read-only)
+    #[serde(default, skip_serializing, deserialize_with = "id::option::deserialize")]
+    id: Option<LabelId>,
+    /// Label name
+    name: String,
+    /// Label color
+    #[serde(skip_serializing_if = "Option::is_none")]
+    color: Option<Color>,
+    /// Label position in the list of labels (read-only)
+    #[serde(skip_serializing)]
+    order: Option<u32>,
+    /// Whether the label is marked as a favorite
+    #[serde(default)]
+    favorite: bool
+}
+
+impl Label {
+    /// Creates a new label with the given name.
+    pub fn create(name: &str) -> Label {
+        Label {
+            id: None,
+            name: String::from(name),
+            color: None,
+            order: None,
+            favorite: false
+        }
+    }
+
+    /// Sets the label name.
+    pub fn set_name(&mut self, name: &str) {
+        self.name = String::from(name);
+    }
+
+    /// Sets the label color.
+    pub fn set_color(&mut self, color: Option<Color>) {
+        self.color = color;
+    }
+
+    /// Sets whether the label is marked as a favorite.
+    pub fn set_favorite(&mut self, favorite: bool) {
+        self.favorite = favorite;
+    }
+
+    /// Gets the label identifier.
+    pub fn id(&self) -> &Option<LabelId> {
+        &self.id
+    }
+
+    /// Gets the label name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the label color.
+    pub fn color(&self) -> &Option<Color> {
+        &self.color
+    }
+
+    /// Gets the order to display the label within the list of labels.
+    pub fn order(&self) -> &Option<u32> {
+        &self.order
+    }
+
+    /// Gets whether the label is marked as a favorite.
+    pub fn favorite(&self) -> bool {
+        self.favorite
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate serde_json;
+    use model::label::Label;
+    use model::color::Color;
+
+    #[test]
+    fn create_and_serialize_label() {
+        let mut new_label = Label::create("Food");
+        let json = serde_json::to_string(&new_label).unwrap();
+        println!("{}", json);
+        assert_eq!(json, r#"{"name":"Food","favorite":false}"#);
+
+        new_label.set_color(Some(Color::Red));
+        new_label.set_favorite(true);
+        let json = serde_json::to_string(&new_label).unwrap();
+        println!("{}", json);
+        assert_eq!(json, r#"{"name":"Food","color":31,"favorite":true}"#);
+    }
+
+    #[test]
+    fn deserialize_label() {
+        let json = r#"
+            {
+                "id": 1234,
+                "name": "Food",
+                "color": 31,
+                "order": 1,
+                "favorite": true
+            }
+        "#;
+
+        let label: Label = serde_json::from_str(json).unwrap();
+        assert_eq!(label.name(), "Food");
+        assert_eq!(label.id().unwrap(), 1234);
+        assert_eq!(label.color().unwrap(), Color::Red);
+        assert_eq!(label.order().unwrap(), 1);
+        assert!(label.favorite());
+
+        let json = serde_json::to_string(&label).unwrap();
+        assert!(!json.contains("id"));
+        assert!(!json.contains("order"));
+    }
+
+    #[test]
+    fn deserialize_sparse_label() {
+        let label: Label = serde_json::from_str(r#"{"name": "Food"}"#).unwrap();
+        assert_eq!(label.name(), "Food");
+        assert_eq!(label.id(), &None);
+        assert_eq!(label.color(), &None);
+        assert!(!label.favorite());
+    }
+
+    #[test]
+    fn round_trip_label() {
+        let mut label = Label::create("Food");
+        label.set_color(Some(Color::Teal));
+        label.set_favorite(true);
+
+        let json = serde_json::to_string(&label).unwrap();
+        let deserialized: Label = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, label);
+
+        label.set_name("Groceries");
+        label.set_color(None);
+        label.set_favorite(false);
+        let json = serde_json::to_string(&label).unwrap();
+        assert_eq!(json, r#"{"name":"Groceries","favorite":false}"#);
+        assert_eq!(serde_json::from_str::<Label>(&json).unwrap(), label);
+    }
 
-}
\ No newline at end of file
+    #[test]
+    fn default_label() {
+        assert_eq!(Label::default(), Label::create(""));
+        assert_eq!(Label::default().color(), &None);
+    }
+}
@@ -3,6 +3,35 @@
 //! Contains the data-model structures mapping to Todoist json objects.
 
 pub mod project;
+pub mod project_tree;
 pub mod task;
 pub mod comment;
-pub mod label;
\ No newline at end of file
+pub mod label;
+pub mod section;
+pub mod reminder;
+pub mod color;
+pub mod user;
+pub mod completed;
+pub mod escalation;
+pub mod id;
+
+/// Identifier of a task.
+pub type TaskId = u64;
+
+/// Identifier of a project.
+pub type ProjectId = u64;
+
+/// Identifier of a comment.
+pub type CommentId = u64;
+
+/// Identifier of a label.
+pub type LabelId = u64;
+
+/// Identifier of a section.
+pub type SectionId = u64;
+
+/// Identifier of a reminder.
+pub type ReminderId = u64;
+
+/// Identifier of a user.
+pub type UserId = u64;
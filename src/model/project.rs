@@ -2,19 +2,86 @@
 //!
 //! Module containing project-related structures and utilities.
 
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+use serde_json;
+use model::ProjectId;
+use model::id;
+use model::color::Color;
+
+/// The color used for new projects.
+const DEFAULT_COLOR: Color = Color::Berry;
+
+fn default_color() -> Color {
+    DEFAULT_COLOR
+}
+
+fn is_default_color(color: &Color) -> bool {
+    *color == DEFAULT_COLOR
+}
+
+fn is_false(value: &bool) -> bool {
+    !*value
+}
+
+/// Request body for updating a project, holding the fields the update endpoint accepts.
+#[derive(Serialize)]
+pub(crate) struct ProjectUpdate<'a> {
+    name: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none", with = "id::option")]
+    parent_id: Option<ProjectId>,
+    color: Color,
+    is_favorite: bool
+}
+
 /// Data model for a project that tasks can be grouped into.
-#[derive(Serialize, Deserialize, Debug)]
+///
+/// Serializing a project gives the body for creating it, which only holds the writable fields
+/// that differ from their defaults. Use `Project::to_update_json` for the body of an update.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct Project {
-    /// Project identifier
-    id: Option<u32>,
+    /// Project identifier (read-only)
+    #[serde(default, skip_serializing, deserialize_with = "id::option::deserialize")]
+    id: Option<ProjectId>,
     /// Project name
     name: String,
     /// Project position in the list of projects (read-only)
+    #[serde(skip_serializing)]
     order: Option<u32>,
     /// Value from 1 to 4 for the project indentation level (read-only)
+    #[serde(skip_serializing)]
     indent: Option<u32>,
-    /// The number of project comments
-    comment_count: Option<u32>
+    /// The number of project comments (read-only)
+    #[serde(skip_serializing)]
+    comment_count: Option<u32>,
+    /// Identifier of the parent project for nested projects
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "id::option")]
+    parent_id: Option<ProjectId>,
+    /// Project color
+    #[serde(default = "default_color", skip_serializing_if = "is_default_color")]
+    color: Color,
+    /// Whether the project is marked as a favorite
+    #[serde(default, alias = "favorite", skip_serializing_if = "is_false")]
+    is_favorite: bool,
+    /// Whether this is the user's inbox project (read-only)
+    #[serde(default, skip_serializing, alias = "inbox_project")]
+    is_inbox_project: bool,
+    /// Whether this is the team inbox project (read-only)
+    #[serde(default, skip_serializing, alias = "team_inbox")]
+    is_team_inbox: bool,
+    /// Whether the project is shared with other users (read-only)
+    #[serde(default, skip_serializing, alias = "shared")]
+    is_shared: bool,
+    /// URL to access this project in Todoist web interface (read-only)
+    #[serde(default, skip_serializing)]
+    url: Option<String>,
+    /// Identifier of the workspace the project belongs to for business accounts (read-only)
+    #[serde(default, skip_serializing, deserialize_with = "id::option::deserialize")]
+    workspace_id: Option<u64>,
+    /// Identifier of the workspace folder grouping the project for business accounts (read-only)
+    #[serde(default, skip_serializing, deserialize_with = "id::option::deserialize")]
+    folder_id: Option<u64>
 }
 
 impl Project {
@@ -25,7 +92,39 @@ impl Project {
             name: String::from(name),
             order: None,
             indent: None,
-            comment_count: None
+            comment_count: None,
+            parent_id: None,
+            color: DEFAULT_COLOR,
+            is_favorite: false,
+            is_inbox_project: false,
+            is_team_inbox: false,
+            is_shared: false,
+            url: None,
+            workspace_id: None,
+            folder_id: None
+        }
+    }
+
+    /// Starts building a new project with the given name.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::project::Project;
+    /// use todoist_rest::model::color::Color;
+    ///
+    /// let project = Project::builder("Groceries")
+    ///     .parent_id(1234)
+    ///     .color(Color::Green)
+    ///     .favorite(true)
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(project.parent_id(), &Some(1234));
+    /// assert!(project.is_favorite());
+    /// ```
+    pub fn builder(name: &str) -> ProjectBuilder {
+        ProjectBuilder {
+            project: Project::create(name)
         }
     }
 
@@ -33,13 +132,56 @@ impl Project {
         self.name = String::from(name);
     }
 
+    /// Sets the identifier of the parent project, making this project a sub-project.
+    pub fn set_parent_id(&mut self, parent_id: Option<ProjectId>) {
+        self.parent_id = parent_id;
+    }
+
+    /// Sets the project color.
+    pub fn set_color(&mut self, color: Color) {
+        self.color = color;
+    }
+
+    /// Sets whether the project is marked as a favorite.
+    pub fn set_favorite(&mut self, is_favorite: bool) {
+        self.is_favorite = is_favorite;
+    }
+
+    /// Gets the JSON body for updating the project.
+    ///
+    /// Unlike the create body, the color and the favorite flag are always included, so that an
+    /// update can set them back to berry and not favorite.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `serde_json::Error` if the project could not be serialized.
+    pub fn to_update_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(&self.update_body())
+    }
+
+    /// Gets the body for updating the project, as given by `Project::to_update_json`.
+    pub(crate) fn update_body(&self) -> ProjectUpdate<'_> {
+        ProjectUpdate {
+            name: &self.name,
+            parent_id: self.parent_id,
+            color: self.color,
+            is_favorite: self.is_favorite
+        }
+    }
+
+    /// Sets the project identifier, as done by the server when the project is created.
+    #[cfg(feature = "mock")]
+    pub(crate) fn set_id(&mut self, id: ProjectId) {
+        self.id = Some(id);
+    }
+
     /// Gets the project name.
     pub fn name(&self) -> &str {
         &self.name
     }
 
     /// Gets the project identifier.
-    pub fn id(&self) -> &Option<u32> {
+    pub fn id(&self) -> &Option<ProjectId> {
         &self.id
     }
 
@@ -57,19 +199,252 @@ impl Project {
     pub fn comment_count(&self) -> &Option<u32> {
         &self.comment_count
     }
+
+    /// Gets the identifier of the parent project, if this is a sub-project.
+    pub fn parent_id(&self) -> &Option<ProjectId> {
+        &self.parent_id
+    }
+
+    /// Gets whether the project is nested under a parent project.
+    pub fn is_child(&self) -> bool {
+        self.parent_id.is_some()
+    }
+
+    /// Gets the project color.
+    pub fn color(&self) -> Color {
+        self.color
+    }
+
+    /// Gets whether the project is marked as a favorite.
+    pub fn is_favorite(&self) -> bool {
+        self.is_favorite
+    }
+
+    /// Gets whether this is the user's inbox project.
+    pub fn is_inbox_project(&self) -> bool {
+        self.is_inbox_project
+    }
+
+    /// Gets whether this is the team inbox project.
+    pub fn is_team_inbox(&self) -> bool {
+        self.is_team_inbox
+    }
+
+    /// Gets whether this is an inbox, either the user's inbox or the team inbox.
+    pub fn is_inbox(&self) -> bool {
+        self.is_inbox_project || self.is_team_inbox
+    }
+
+    /// Gets whether the project is shared with other users.
+    pub fn is_shared(&self) -> bool {
+        self.is_shared
+    }
+
+    /// Gets the URL on the Todoist site where the project can be viewed.
+    pub fn url(&self) -> &Option<String> {
+        &self.url
+    }
+
+    /// Gets the identifier of the workspace the project belongs to for business accounts.
+    pub fn workspace_id(&self) -> &Option<u64> {
+        &self.workspace_id
+    }
+
+    /// Gets the identifier of the workspace folder grouping the project for business accounts.
+    pub fn folder_id(&self) -> &Option<u64> {
+        &self.folder_id
+    }
+
+    /// Checks whether the project satisfies every criterion set on the filter.
+    pub fn matches_filter(&self, filter: &ProjectFilter) -> bool {
+        filter.color.is_none_or(|color| self.color == color) &&
+            filter.is_favorite.is_none_or(|is_favorite| self.is_favorite == is_favorite) &&
+            filter.indent.is_none_or(|indent| self.indent == Some(indent)) &&
+            filter.has_parent.is_none_or(|has_parent| self.is_child() == has_parent) &&
+            filter.name_contains.as_ref().is_none_or(|text| {
+                self.name.to_lowercase().contains(&text.to_lowercase())
+            })
+    }
 }
 
+/// Criteria for selecting projects from a list that is already loaded, without querying the API.
+///
+/// A project matches the filter when it satisfies every criterion that is set.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProjectFilter {
+    /// Only match projects whose name contains this text, ignoring case
+    name_contains: Option<String>,
+    /// Only match projects with this color
+    color: Option<Color>,
+    /// Only match projects that are or are not marked as a favorite
+    is_favorite: Option<bool>,
+    /// Only match projects at this indentation level
+    indent: Option<u32>,
+    /// Only match projects that are or are not nested under a parent project
+    has_parent: Option<bool>
+}
+
+impl ProjectFilter {
+    /// Creates a new filter matching all projects.
+    pub fn new() -> ProjectFilter {
+        ProjectFilter::default()
+    }
+
+    /// Only match projects whose name contains this text, ignoring case.
+    pub fn set_name_contains(&mut self, text: Option<&str>) {
+        self.name_contains = text.map(String::from);
+    }
+
+    /// Only match projects with this color.
+    pub fn set_color(&mut self, color: Option<Color>) {
+        self.color = color;
+    }
+
+    /// Only match projects that are or are not marked as a favorite.
+    pub fn set_is_favorite(&mut self, is_favorite: Option<bool>) {
+        self.is_favorite = is_favorite;
+    }
+
+    /// Only match projects at this indentation level.
+    pub fn set_indent(&mut self, indent: Option<u32>) {
+        self.indent = indent;
+    }
+
+    /// Only match projects that are or are not nested under a parent project.
+    pub fn set_has_parent(&mut self, has_parent: Option<bool>) {
+        self.has_parent = has_parent;
+    }
+
+    /// Gets the text that the name of matched projects contains.
+    pub fn name_contains(&self) -> &Option<String> {
+        &self.name_contains
+    }
+
+    /// Gets the color of matched projects.
+    pub fn color(&self) -> &Option<Color> {
+        &self.color
+    }
+
+    /// Gets whether matched projects are marked as a favorite.
+    pub fn is_favorite(&self) -> &Option<bool> {
+        &self.is_favorite
+    }
+
+    /// Gets the indentation level of matched projects.
+    pub fn indent(&self) -> &Option<u32> {
+        &self.indent
+    }
+
+    /// Gets whether matched projects are nested under a parent project.
+    pub fn has_parent(&self) -> &Option<bool> {
+        &self.has_parent
+    }
+}
+
+impl Default for Project {
+    /// Creates a new project with an empty name, like `Project::create("")`.
+    fn default() -> Project {
+        Project::create("")
+    }
+}
+
+impl FromStr for Project {
+    type Err = serde_json::Error;
+
+    /// Parses a project from its JSON representation returned by the API.
+    fn from_str(json: &str) -> Result<Project, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+}
+
+/// Builder for a new project, started with `Project::builder`.
+#[derive(Debug)]
+pub struct ProjectBuilder {
+    /// The project being built
+    project: Project
+}
+
+impl ProjectBuilder {
+    /// Sets the identifier of the parent project, making the project a sub-project.
+    pub fn parent_id(mut self, parent_id: ProjectId) -> ProjectBuilder {
+        self.project.set_parent_id(Some(parent_id));
+        self
+    }
+
+    /// Sets the project color.
+    pub fn color(mut self, color: Color) -> ProjectBuilder {
+        self.project.set_color(color);
+        self
+    }
+
+    /// Sets whether the project is marked as a favorite.
+    pub fn favorite(mut self, is_favorite: bool) -> ProjectBuilder {
+        self.project.set_favorite(is_favorite);
+        self
+    }
+
+    /// Builds the project.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ProjectBuildError` if the name is empty.
+    pub fn build(self) -> Result<Project, ProjectBuildError> {
+        if self.project.name.trim().is_empty() {
+            return Err(ProjectBuildError::EmptyName);
+        }
+
+        Ok(self.project)
+    }
+}
+
+/// Error returned when a `ProjectBuilder` is given invalid values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProjectBuildError {
+    /// The project name is empty or only whitespace
+    EmptyName
+}
+
+impl fmt::Display for ProjectBuildError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ProjectBuildError::EmptyName => write!(f, "project name is empty")
+        }
+    }
+}
+
+impl Error for ProjectBuildError {}
+
 #[cfg(test)]
 mod tests {
     extern crate serde_json;
-    use model::project::Project;
+    use model::project::{Project, ProjectBuildError, ProjectFilter};
+    use model::color::Color;
 
     #[test]
     fn create_and_serialize_project() {
         let new_project = Project::create("Test Project");
         let json = serde_json::to_string(&new_project).unwrap();
         println!("{}", json);
-        assert!(json.contains("\"name\":\"Test Project\""));
+        assert_eq!(json, r#"{"name":"Test Project"}"#);
+    }
+
+    #[test]
+    fn serialize_project_with_parent() {
+        let mut new_project = Project::create("Sub Project");
+        assert!(!new_project.is_child());
+        assert!(!serde_json::to_string(&new_project).unwrap().contains("parent_id"));
+
+        new_project.set_parent_id(Some(1234));
+        assert!(new_project.is_child());
+        let json = serde_json::to_string(&new_project).unwrap();
+        println!("{}", json);
+        assert_eq!(json, r#"{"name":"Sub Project","parent_id":1234}"#);
+        assert!(!json.contains("null"));
+
+        let project: Project = serde_json::from_str(r#"{"id": 2345, "name": "Sub Project",
+                                                        "parent_id": 1234}"#).unwrap();
+        assert_eq!(project.parent_id(), &Some(1234));
+        assert!(project.is_child());
     }
 
     #[test]
@@ -90,5 +465,292 @@ mod tests {
         assert_eq!(project.comment_count().unwrap(), 0);
         assert_eq!(project.order().unwrap(), 1);
         assert_eq!(project.indent().unwrap(), 1);
+        assert_eq!(project.color(), Color::Berry);
+        assert!(!project.is_favorite());
+        assert!(!project.is_inbox_project());
+        assert!(!project.is_team_inbox());
+        assert!(!project.is_shared());
+    }
+
+    #[test]
+    fn deserialize_inbox_project() {
+        let json = r#"
+            {
+                "id": 1234,
+                "name": "Inbox",
+                "comment_count": 0,
+                "order": 0,
+                "indent": 1,
+                "is_inbox_project": true,
+                "is_shared": false
+            }
+        "#;
+
+        let project: Project = serde_json::from_str(json).unwrap();
+        assert!(project.is_inbox_project());
+        assert!(!project.is_team_inbox());
+        assert!(project.is_inbox());
+        assert!(!project.is_shared());
+
+        let json = serde_json::to_string(&project).unwrap();
+        println!("{}", json);
+        assert!(!json.contains("is_inbox_project"));
+
+        let json = r#"{"id": 1235, "name": "Team Inbox", "is_team_inbox": true, "is_shared": true}"#;
+        let team_inbox: Project = serde_json::from_str(json).unwrap();
+        assert!(team_inbox.is_inbox());
+        assert!(team_inbox.is_shared());
+    }
+
+    #[test]
+    fn deserialize_project_with_new_field_names() {
+        let legacy = r#"
+            {
+                "id": 1234,
+                "name": "Inbox",
+                "favorite": true,
+                "inbox_project": true,
+                "team_inbox": false,
+                "shared": true
+            }
+        "#;
+        let current = r#"
+            {
+                "id": 1234,
+                "name": "Inbox",
+                "is_favorite": true,
+                "is_inbox_project": true,
+                "is_team_inbox": false,
+                "is_shared": true
+            }
+        "#;
+
+        let legacy: Project = serde_json::from_str(legacy).unwrap();
+        let current: Project = serde_json::from_str(current).unwrap();
+        assert!(legacy.is_favorite());
+        assert!(legacy.is_inbox_project());
+        assert!(legacy.is_shared());
+        assert_eq!(legacy.is_favorite(), current.is_favorite());
+        assert_eq!(legacy.is_inbox_project(), current.is_inbox_project());
+        assert_eq!(legacy.is_team_inbox(), current.is_team_inbox());
+        assert_eq!(legacy.is_shared(), current.is_shared());
+    }
+
+    #[test]
+    fn deserialize_sparse_project() {
+        let project: Project = serde_json::from_str(r#"{"name": "Movies to watch"}"#).unwrap();
+        assert_eq!(project.name(), "Movies to watch");
+        assert_eq!(project.id(), &None);
+        assert_eq!(project.color(), Color::Berry);
+        assert!(!project.is_favorite());
+        assert!(!project.is_shared());
+        assert!(!project.is_inbox());
+        assert_eq!(project.url(), &None);
+    }
+
+    #[test]
+    fn deserialize_project_url() {
+        let json = r#"
+            {
+                "id": 1234,
+                "name": "Movies to watch",
+                "url": "https://todoist.com/showProject?id=1234"
+            }
+        "#;
+
+        let project: Project = serde_json::from_str(json).unwrap();
+        assert_eq!(project.url(), &Some(String::from("https://todoist.com/showProject?id=1234")));
+        assert_eq!(Project::create("Test Project").url(), &None);
+
+        let json = serde_json::to_string(&project).unwrap();
+        assert!(!json.contains("url"));
+    }
+
+    #[test]
+    fn deserialize_business_project() {
+        let json = r#"
+            {
+                "id": 1234,
+                "name": "Team roadmap",
+                "parent_id": null,
+                "workspace_id": 42,
+                "folder_id": 7,
+                "collaborator_role_default": "READ_WRITE"
+            }
+        "#;
+
+        let project: Project = serde_json::from_str(json).unwrap();
+        assert_eq!(project.parent_id(), &None);
+        assert_eq!(project.workspace_id(), &Some(42));
+        assert_eq!(project.folder_id(), &Some(7));
+
+        let json = serde_json::to_string(&project).unwrap();
+        assert!(!json.contains("workspace_id"));
+        assert!(!json.contains("folder_id"));
+    }
+
+    #[test]
+    fn deserialize_project_with_color_and_favorite() {
+        let json = r#"
+            {
+                "id": 1234,
+                "name": "Movies to watch",
+                "comment_count": 0,
+                "order": 1,
+                "indent": 1,
+                "color": 47,
+                "is_favorite": true
+            }
+        "#;
+
+        let project: Project = serde_json::from_str(json).unwrap();
+        assert_eq!(project.color(), Color::Charcoal);
+        assert!(project.is_favorite());
+    }
+
+    #[test]
+    fn serialize_update_with_default_color_and_favorite() {
+        let json = r#"{"id": 1234, "name": "Movies", "color": 47, "is_favorite": true}"#;
+        let mut project: Project = serde_json::from_str(json).unwrap();
+        project.set_color(Color::Berry);
+        project.set_favorite(false);
+
+        let json = project.to_update_json().unwrap();
+        assert_eq!(json, r#"{"name":"Movies","color":30,"is_favorite":false}"#);
+        assert_eq!(serde_json::to_string(&project).unwrap(), r#"{"name":"Movies"}"#);
+    }
+
+    #[test]
+    fn serialize_fetched_project() {
+        let json = r#"
+            {
+                "id": 1234,
+                "name": "Movies to watch",
+                "comment_count": 3,
+                "order": 2,
+                "indent": 1,
+                "parent_id": 1000,
+                "color": 47,
+                "is_favorite": true,
+                "is_shared": true,
+                "url": "https://todoist.com/showProject?id=1234",
+                "workspace_id": 77
+            }
+        "#;
+
+        let project: Project = serde_json::from_str(json).unwrap();
+        let json = serde_json::to_string(&project).unwrap();
+        println!("{}", json);
+        assert_eq!(json,
+                   r#"{"name":"Movies to watch","parent_id":1000,"color":47,"is_favorite":true}"#);
+
+        let resent: Project = serde_json::from_str(&json).unwrap();
+        assert_eq!(resent.name(), project.name());
+        assert_eq!(resent.color(), project.color());
+        assert_eq!(resent.is_favorite(), project.is_favorite());
+
+        let json = r#"{"id": 1235, "name": "Inbox", "comment_count": 0, "order": 0, "indent": 1,
+                       "is_inbox_project": true}"#;
+        let project: Project = serde_json::from_str(json).unwrap();
+        assert_eq!(serde_json::to_string(&project).unwrap(), r#"{"name":"Inbox"}"#);
+    }
+
+    #[test]
+    fn update_project_color_and_favorite() {
+        let mut project = Project::create("Test Project");
+        assert_eq!(project.color(), Color::Berry);
+        assert!(!project.is_favorite());
+
+        project.set_color(Color::Blue);
+        project.set_favorite(true);
+        let json = serde_json::to_string(&project).unwrap();
+        println!("{}", json);
+        assert_eq!(json, r#"{"name":"Test Project","color":41,"is_favorite":true}"#);
+    }
+
+    #[test]
+    fn build_project() {
+        let project = Project::builder("Groceries")
+            .parent_id(1234)
+            .color(Color::Green)
+            .favorite(true)
+            .build()
+            .unwrap();
+
+        let json = serde_json::to_string(&project).unwrap();
+        println!("{}", json);
+        assert_eq!(json, r#"{"name":"Groceries","parent_id":1234,"color":36,"is_favorite":true}"#);
+
+        assert_eq!(Project::builder("  ").build().unwrap_err(), ProjectBuildError::EmptyName);
+    }
+
+    #[test]
+    fn default_project() {
+        let project = Project::default();
+        assert_eq!(project, Project::create(""));
+        assert_eq!(project.color(), Color::Berry);
+
+        let project = Project { name: String::from("Errands"), ..Default::default() };
+        assert_eq!(project, Project::create("Errands"));
+    }
+
+    #[test]
+    fn parse_project() {
+        let project: Project = r#"{"id": 2345, "name": "Errands", "color": 41, "favorite": true}"#
+            .parse().unwrap();
+        assert_eq!(project.id(), &Some(2345));
+        assert_eq!(project.color(), Color::Blue);
+
+        let json = serde_json::to_string(&project).unwrap();
+        assert_eq!(json, r#"{"name":"Errands","color":41,"is_favorite":true}"#);
+        let parsed: Project = json.parse().unwrap();
+        assert_eq!(parsed.name(), "Errands");
+        assert_eq!(parsed.color(), Color::Blue);
+        assert!(parsed.is_favorite());
+
+        assert!("not json".parse::<Project>().is_err());
+    }
+
+    #[test]
+    fn match_project_filter() {
+        let json = r#"
+            [
+                {"id": 1, "name": "Inbox", "indent": 1, "color": 48},
+                {"id": 2, "name": "Work", "indent": 1, "color": 41, "is_favorite": true},
+                {"id": 3, "name": "Work meetings", "indent": 2, "parent_id": 2, "color": 41},
+                {"id": 4, "name": "Homework", "indent": 1, "color": 36, "is_favorite": true}
+            ]
+        "#;
+        let projects: Vec<Project> = serde_json::from_str(json).unwrap();
+        let matching_ids = |filter: &ProjectFilter| -> Vec<u64> {
+            projects.iter()
+                .filter(|project| project.matches_filter(filter))
+                .map(|project| project.id().unwrap())
+                .collect()
+        };
+
+        let mut filter = ProjectFilter::new();
+        assert_eq!(matching_ids(&filter), vec![1, 2, 3, 4]);
+        filter.set_name_contains(Some("work"));
+        assert_eq!(matching_ids(&filter), vec![2, 3, 4]);
+        filter.set_color(Some(Color::Blue));
+        assert_eq!(matching_ids(&filter), vec![2, 3]);
+        filter.set_has_parent(Some(true));
+        assert_eq!(matching_ids(&filter), vec![3]);
+        filter.set_is_favorite(Some(true));
+        assert!(matching_ids(&filter).is_empty());
+
+        let mut filter = ProjectFilter::new();
+        filter.set_is_favorite(Some(true));
+        assert_eq!(matching_ids(&filter), vec![2, 4]);
+        filter.set_is_favorite(Some(false));
+        assert_eq!(matching_ids(&filter), vec![1, 3]);
+
+        let mut filter = ProjectFilter::new();
+        filter.set_indent(Some(1));
+        filter.set_has_parent(Some(false));
+        assert_eq!(matching_ids(&filter), vec![1, 2, 4]);
+        filter.set_indent(Some(2));
+        assert!(matching_ids(&filter).is_empty());
     }
 }
@@ -0,0 +1,282 @@
+//! # Project Tree
+//!
+//! Module containing utilities for arranging projects in their hierarchy.
+
+use std::collections::HashMap;
+use model::ProjectId;
+use model::project::Project;
+
+/// A project along with the projects nested under it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProjectNode {
+    /// The project
+    project: Project,
+    /// The projects whose parent is this project, in the order they were listed
+    children: Vec<ProjectNode>
+}
+
+impl ProjectNode {
+    /// Gets the project.
+    pub fn project(&self) -> &Project {
+        &self.project
+    }
+
+    /// Gets the nodes of the projects nested directly under this project.
+    pub fn children(&self) -> &[ProjectNode] {
+        &self.children
+    }
+
+    /// Iterates over this project and all projects nested under it, each parent before its
+    /// children.
+    pub fn depth_first_iter(&self) -> impl Iterator<Item = &Project> {
+        DepthFirstIter { stack: vec![self] }
+    }
+}
+
+/// The root projects of a workspace folder, an optional second level of grouping above the
+/// project trees for business accounts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FolderGroup {
+    /// Identifier of the folder, or `None` for the root projects outside of any folder
+    folder_id: Option<u64>,
+    /// The trees of the root projects in the folder, in the order they were listed
+    projects: Vec<ProjectNode>
+}
+
+impl FolderGroup {
+    /// Gets the identifier of the folder, or `None` for the root projects outside of any folder.
+    pub fn folder_id(&self) -> &Option<u64> {
+        &self.folder_id
+    }
+
+    /// Gets the trees of the root projects in the folder.
+    pub fn projects(&self) -> &[ProjectNode] {
+        &self.projects
+    }
+}
+
+/// Iterator over the projects of a tree, each parent before its children.
+struct DepthFirstIter<'a> {
+    /// The nodes left to visit, the next one last
+    stack: Vec<&'a ProjectNode>
+}
+
+impl<'a> Iterator for DepthFirstIter<'a> {
+    type Item = &'a Project;
+
+    fn next(&mut self) -> Option<&'a Project> {
+        let node = self.stack.pop()?;
+        self.stack.extend(node.children.iter().rev());
+        Some(&node.project)
+    }
+}
+
+/// Arranges a flat list of projects into trees following their parent identifiers.
+///
+/// Projects without a parent are the roots of the trees. So are orphan projects, whose parent
+/// is not in the list, and one project of each cycle of parents. Roots and children keep the
+/// order in which they were listed.
+///
+/// # Example
+///
+/// ```
+/// extern crate serde_json;
+/// # extern crate todoist_rest;
+/// use todoist_rest::model::project::Project;
+/// use todoist_rest::model::project_tree::build_project_tree;
+///
+/// # fn main() {
+/// let projects: Vec<Project> = serde_json::from_str(r#"[
+///     {"id": 1, "name": "Work"},
+///     {"id": 2, "name": "Meetings", "parent_id": 1}
+/// ]"#).unwrap();
+///
+/// let tree = build_project_tree(projects);
+/// assert_eq!(tree.len(), 1);
+/// assert_eq!(tree[0].children()[0].project().name(), "Meetings");
+/// # }
+/// ```
+pub fn build_project_tree(projects: Vec<Project>) -> Vec<ProjectNode> {
+    let indexes: HashMap<ProjectId, usize> = projects.iter()
+        .enumerate()
+        .filter_map(|(index, project)| project.id().map(|id| (id, index)))
+        .collect();
+    let parents: Vec<Option<usize>> = projects.iter()
+        .enumerate()
+        .map(|(index, project)| {
+            project.parent_id()
+                .and_then(|parent_id| indexes.get(&parent_id).cloned())
+                .filter(|parent| *parent != index)
+        })
+        .collect();
+
+    let mut children = vec![vec![]; projects.len()];
+    for (index, parent) in parents.iter().enumerate() {
+        if let Some(parent) = *parent {
+            children[parent].push(index);
+        }
+    }
+
+    let mut projects: Vec<Option<Project>> = projects.into_iter().map(Some).collect();
+    let mut roots: Vec<ProjectNode> = (0..projects.len())
+        .filter(|index| parents[*index].is_none())
+        .filter_map(|index| build_node(index, &mut projects, &children))
+        .collect();
+
+    // Projects that are still left are part of a cycle of parents, which is broken at the
+    // first listed project of the cycle.
+    for index in 0..projects.len() {
+        if let Some(node) = build_node(index, &mut projects, &children) {
+            roots.push(node);
+        }
+    }
+
+    roots
+}
+
+/// Groups project trees by the workspace folder of their root project.
+///
+/// Workspace projects of business accounts have no parent but may be grouped in a folder. Each
+/// group holds the trees whose root project has the same `folder_id`, and the trees whose root
+/// is in no folder share the group with `None`. Groups are in the order of their first tree, and
+/// trees keep their order within a group. Only the root projects are grouped; nested projects
+/// stay under their parent whatever their folder.
+///
+/// # Example
+///
+/// ```
+/// extern crate serde_json;
+/// # extern crate todoist_rest;
+/// use todoist_rest::model::project::Project;
+/// use todoist_rest::model::project_tree::{build_project_tree, group_by_folder};
+///
+/// # fn main() {
+/// let projects: Vec<Project> = serde_json::from_str(r#"[
+///     {"id": 1, "name": "Roadmap", "workspace_id": 42, "folder_id": 7},
+///     {"id": 2, "name": "Inbox"},
+///     {"id": 3, "name": "Hiring", "workspace_id": 42, "folder_id": 7}
+/// ]"#).unwrap();
+///
+/// let groups = group_by_folder(build_project_tree(projects));
+/// assert_eq!(groups.len(), 2);
+/// assert_eq!(groups[0].folder_id(), &Some(7));
+/// assert_eq!(groups[0].projects()[1].project().name(), "Hiring");
+/// assert_eq!(groups[1].folder_id(), &None);
+/// # }
+/// ```
+pub fn group_by_folder(trees: Vec<ProjectNode>) -> Vec<FolderGroup> {
+    let mut groups: Vec<FolderGroup> = vec![];
+
+    for tree in trees {
+        let folder_id = *tree.project.folder_id();
+
+        match groups.iter_mut().find(|group| group.folder_id == folder_id) {
+            Some(group) => group.projects.push(tree),
+            None => groups.push(FolderGroup { folder_id, projects: vec![tree] })
+        }
+    }
+
+    groups
+}
+
+/// Builds the node of the project at the index, unless the project was already placed in a tree.
+fn build_node(index: usize, projects: &mut [Option<Project>], children: &[Vec<usize>])
+        -> Option<ProjectNode> {
+    let project = projects[index].take()?;
+    let children = children[index].iter()
+        .filter_map(|child| build_node(*child, projects, children))
+        .collect();
+
+    Some(ProjectNode { project, children })
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate serde_json;
+    use model::project::Project;
+    use model::project_tree::{ProjectNode, build_project_tree, group_by_folder};
+
+    /// Gets the names of the projects of the tree, each parent before its children.
+    fn names(node: &ProjectNode) -> Vec<&str> {
+        node.depth_first_iter().map(|project| project.name()).collect()
+    }
+
+    #[test]
+    fn build_multi_level_tree() {
+        let json = r#"
+            [
+                {"id": 1, "name": "Work"},
+                {"id": 2, "name": "Meetings", "parent_id": 1},
+                {"id": 3, "name": "Personal"},
+                {"id": 4, "name": "Weekly", "parent_id": 2},
+                {"id": 5, "name": "Reports", "parent_id": 1},
+                {"id": 6, "name": "Standup", "parent_id": 4}
+            ]
+        "#;
+
+        let projects: Vec<Project> = serde_json::from_str(json).unwrap();
+        let tree = build_project_tree(projects);
+        assert_eq!(tree.len(), 2);
+        assert_eq!(names(&tree[0]), vec!["Work", "Meetings", "Weekly", "Standup", "Reports"]);
+        assert_eq!(names(&tree[1]), vec!["Personal"]);
+
+        let work = &tree[0];
+        assert_eq!(work.children().len(), 2);
+        assert_eq!(work.children()[0].children()[0].children()[0].project().id(), &Some(6));
+        assert!(work.children()[1].children().is_empty());
+    }
+
+    #[test]
+    fn build_tree_with_orphans_and_cycles() {
+        let json = r#"
+            [
+                {"id": 1, "name": "Orphan", "parent_id": 99},
+                {"id": 2, "name": "Child of orphan", "parent_id": 1},
+                {"id": 3, "name": "Own parent", "parent_id": 3},
+                {"id": 4, "name": "Cycle A", "parent_id": 5},
+                {"id": 5, "name": "Cycle B", "parent_id": 4},
+                {"name": "Unsaved"}
+            ]
+        "#;
+
+        let projects: Vec<Project> = serde_json::from_str(json).unwrap();
+        let tree = build_project_tree(projects);
+        let roots: Vec<Vec<&str>> = tree.iter().map(names).collect();
+        assert_eq!(roots, vec![
+            vec!["Orphan", "Child of orphan"],
+            vec!["Own parent"],
+            vec!["Unsaved"],
+            vec!["Cycle A", "Cycle B"]
+        ]);
+
+        assert!(build_project_tree(vec![]).is_empty());
+    }
+
+    #[test]
+    fn group_business_projects_by_folder() {
+        let json = r#"
+            [
+                {"id": 1, "name": "Inbox", "inbox_project": true},
+                {"id": 10, "name": "Roadmap", "workspace_id": 42, "folder_id": 7,
+                 "collaborator_role_default": "READ_WRITE"},
+                {"id": 11, "name": "Q1", "parent_id": 10, "workspace_id": 42, "folder_id": null},
+                {"id": 20, "name": "Hiring", "workspace_id": 42, "folder_id": "8"},
+                {"id": 12, "name": "Launch", "workspace_id": 42, "folder_id": 7},
+                {"id": 30, "name": "Archive", "workspace_id": 42}
+            ]
+        "#;
+
+        let projects: Vec<Project> = serde_json::from_str(json).unwrap();
+        let groups = group_by_folder(build_project_tree(projects));
+        let folders: Vec<(Option<u64>, Vec<Vec<&str>>)> = groups.iter()
+            .map(|group| (*group.folder_id(), group.projects().iter().map(names).collect()))
+            .collect();
+        assert_eq!(folders, vec![
+            (None, vec![vec!["Inbox"], vec!["Archive"]]),
+            (Some(7), vec![vec!["Roadmap", "Q1"], vec!["Launch"]]),
+            (Some(8), vec![vec!["Hiring"]])
+        ]);
+
+        assert!(group_by_folder(vec![]).is_empty());
+    }
+}
@@ -0,0 +1,244 @@
+//! # Reminder
+//!
+//! Module containing reminder-related structures and utilities.
+
+use model::{ReminderId, TaskId, UserId};
+use model::id;
+use model::task::Due;
+
+/// The way a reminder is triggered.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ReminderType {
+    /// Triggered a number of minutes before the task is due
+    Relative,
+    /// Triggered at a fixed date and time
+    Absolute,
+    /// Triggered when arriving at or leaving a location
+    Location
+}
+
+/// Whether a location reminder is triggered when arriving at or leaving the location.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum LocationTrigger {
+    /// Triggered when arriving at the location
+    OnEnter,
+    /// Triggered when leaving the location
+    OnLeave
+}
+
+/// What triggers a reminder, along with the fields that only apply to that kind of reminder.
+///
+/// Serialized with its `ReminderType` as the "type" field, next to the fields of the variant.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum ReminderTrigger {
+    /// Triggered a number of minutes before the task is due
+    Relative {
+        /// Number of minutes before the task is due
+        #[serde(alias = "minute_offset")]
+        mm_offset: i32
+    },
+    /// Triggered at a fixed date and time
+    Absolute {
+        /// When the reminder is triggered
+        due: Due
+    },
+    /// Triggered when arriving at or leaving a location
+    Location {
+        /// Name of the location
+        name: String,
+        /// Latitude of the location
+        loc_lat: String,
+        /// Longitude of the location
+        loc_long: String,
+        /// Whether the reminder is triggered when arriving at or leaving the location
+        loc_trigger: LocationTrigger,
+        /// Radius around the location in meters
+        radius: u32
+    }
+}
+
+impl ReminderTrigger {
+    /// Gets the way the reminder is triggered.
+    pub fn reminder_type(&self) -> ReminderType {
+        match *self {
+            ReminderTrigger::Relative { .. } => ReminderType::Relative,
+            ReminderTrigger::Absolute { .. } => ReminderType::Absolute,
+            ReminderTrigger::Location { .. } => ReminderType::Location
+        }
+    }
+}
+
+/// Data model for a reminder of a task (Todoist Premium only).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Reminder {
+    /// Reminder identifier (read-only)
+    #[serde(default, skip_serializing, deserialize_with = "id::option::deserialize")]
+    id: Option<ReminderId>,
+    /// Identifier of the task the reminder is for
+    #[serde(alias = "item_id", with = "id")]
+    task_id: TaskId,
+    /// What triggers the reminder
+    #[serde(flatten)]
+    trigger: ReminderTrigger,
+    /// Identifier of the user to notify
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "id::option")]
+    notify_uid: Option<UserId>
+}
+
+impl Reminder {
+    /// Creates a new reminder for a task.
+    pub fn create(task_id: TaskId, trigger: ReminderTrigger) -> Reminder {
+        Reminder {
+            id: None,
+            task_id,
+            trigger,
+            notify_uid: None
+        }
+    }
+
+    /// Sets what triggers the reminder.
+    pub fn set_trigger(&mut self, trigger: ReminderTrigger) {
+        self.trigger = trigger;
+    }
+
+    /// Sets the identifier of the user to notify.
+    pub fn set_notify_uid(&mut self, notify_uid: Option<UserId>) {
+        self.notify_uid = notify_uid;
+    }
+
+    /// Gets the reminder identifier.
+    pub fn id(&self) -> &Option<ReminderId> {
+        &self.id
+    }
+
+    /// Gets the identifier of the task the reminder is for.
+    pub fn task_id(&self) -> TaskId {
+        self.task_id
+    }
+
+    /// Gets what triggers the reminder.
+    pub fn trigger(&self) -> &ReminderTrigger {
+        &self.trigger
+    }
+
+    /// Gets the way the reminder is triggered.
+    pub fn reminder_type(&self) -> ReminderType {
+        self.trigger.reminder_type()
+    }
+
+    /// Gets when an absolute reminder is triggered.
+    pub fn due(&self) -> Option<&Due> {
+        match self.trigger {
+            ReminderTrigger::Absolute { ref due } => Some(due),
+            _ => None
+        }
+    }
+
+    /// Gets the number of minutes before the task is due that a relative reminder is triggered.
+    pub fn mm_offset(&self) -> Option<i32> {
+        match self.trigger {
+            ReminderTrigger::Relative { mm_offset } => Some(mm_offset),
+            _ => None
+        }
+    }
+
+    /// Gets the identifier of the user to notify.
+    pub fn notify_uid(&self) -> &Option<UserId> {
+        &self.notify_uid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate serde_json;
+    use model::reminder::{Reminder, ReminderType, ReminderTrigger, LocationTrigger};
+    use model::task::Due;
+
+    #[test]
+    fn create_and_serialize_reminder() {
+        let reminder = Reminder::create(1234, ReminderTrigger::Relative { mm_offset: 30 });
+        let json = serde_json::to_string(&reminder).unwrap();
+        println!("{}", json);
+        assert_eq!(json, r#"{"task_id":1234,"type":"relative","mm_offset":30}"#);
+
+        let mut due = Due::create("2017-12-25T12:00:00Z");
+        due.set_datetime("2017-12-25T12:00:00Z");
+        let mut reminder = Reminder::create(1234, ReminderTrigger::Absolute { due });
+        reminder.set_notify_uid(Some(7));
+        let json = serde_json::to_string(&reminder).unwrap();
+        assert_eq!(json, r#"{"task_id":1234,"type":"absolute","due":{"string":"2017-12-25T12:00:00Z","datetime":"2017-12-25T12:00:00Z"},"notify_uid":7}"#);
+
+        let reminder = Reminder::create(1234, ReminderTrigger::Location {
+            name: String::from("Aldi"),
+            loc_lat: String::from("48.1374"),
+            loc_long: String::from("11.5755"),
+            loc_trigger: LocationTrigger::OnEnter,
+            radius: 100
+        });
+        let json = serde_json::to_string(&reminder).unwrap();
+        assert_eq!(json, r#"{"task_id":1234,"type":"location","name":"Aldi","loc_lat":"48.1374","loc_long":"11.5755","loc_trigger":"on_enter","radius":100}"#);
+    }
+
+    #[test]
+    fn deserialize_reminders() {
+        let json = r#"
+            [
+                {
+                    "id": 5676,
+                    "item_id": 1234,
+                    "type": "relative",
+                    "minute_offset": 30
+                },
+                {
+                    "id": 5677,
+                    "task_id": 1234,
+                    "type": "absolute",
+                    "due": {"string": "christmas", "date": "2017-12-25"}
+                },
+                {
+                    "id": 5678,
+                    "task_id": 1234,
+                    "type": "location",
+                    "notify_uid": 9876543210,
+                    "name": "Aldi",
+                    "loc_lat": "48.1374",
+                    "loc_long": "11.5755",
+                    "loc_trigger": "on_leave",
+                    "radius": 250
+                }
+            ]
+        "#;
+
+        let reminders: Vec<Reminder> = serde_json::from_str(json).unwrap();
+        assert_eq!(reminders[0].task_id(), 1234);
+        assert_eq!(reminders[0].reminder_type(), ReminderType::Relative);
+        assert_eq!(reminders[0].mm_offset(), Some(30));
+        assert!(reminders[0].due().is_none());
+
+        assert_eq!(reminders[1].reminder_type(), ReminderType::Absolute);
+        assert_eq!(reminders[1].due().unwrap().string(), "christmas");
+        assert_eq!(reminders[1].mm_offset(), None);
+
+        let reminder = &reminders[2];
+        assert_eq!(reminder.id(), &Some(5678));
+        assert_eq!(reminder.reminder_type(), ReminderType::Location);
+        assert_eq!(reminder.notify_uid(), &Some(9876543210));
+        match *reminder.trigger() {
+            ReminderTrigger::Location { ref name, loc_trigger, radius, .. } => {
+                assert_eq!(name, "Aldi");
+                assert_eq!(loc_trigger, LocationTrigger::OnLeave);
+                assert_eq!(radius, 250);
+            },
+            ref trigger => panic!("unexpected trigger {:?}", trigger)
+        }
+    }
+
+    #[test]
+    fn deserialize_reminder_without_variant_fields() {
+        let json = r#"{"id": 5678, "task_id": 1234, "type": "location"}"#;
+        assert!(serde_json::from_str::<Reminder>(json).is_err());
+    }
+}
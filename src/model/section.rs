@@ -0,0 +1,116 @@
+//! # Section
+//!
+//! Module containing section-related structures and utilities.
+
+use model::{SectionId, ProjectId};
+use model::id;
+
+/// Data model for a section grouping tasks within a project.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct Section {
+    /// Section identifier (read-only)
+    #[serde(default, skip_serializing, deserialize_with = "id::option::deserialize")]
+    id: Option<SectionId>,
+    /// Identifier of the project the section belongs to
+    #[serde(with = "id")]
+    project_id: ProjectId,
+    /// Section position among the sections of the project
+    #[serde(skip_serializing_if = "Option::is_none")]
+    order: Option<u32>,
+    /// Section name
+    name: String
+}
+
+impl Section {
+    /// Creates a new section with the given name in a project.
+    pub fn create(name: &str, project_id: ProjectId) -> Section {
+        Section {
+            id: None,
+            project_id,
+            order: None,
+            name: String::from(name)
+        }
+    }
+
+    /// Sets the section name.
+    pub fn set_name(&mut self, name: &str) {
+        self.name = String::from(name);
+    }
+
+    /// Sets the position of the section among the sections of the project.
+    pub fn set_order(&mut self, order: Option<u32>) {
+        self.order = order;
+    }
+
+    /// Gets the section identifier.
+    pub fn id(&self) -> &Option<SectionId> {
+        &self.id
+    }
+
+    /// Gets the identifier of the project the section belongs to.
+    pub fn project_id(&self) -> ProjectId {
+        self.project_id
+    }
+
+    /// Gets the position of the section among the sections of the project.
+    pub fn order(&self) -> &Option<u32> {
+        &self.order
+    }
+
+    /// Gets the section name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate serde_json;
+    use model::section::Section;
+
+    #[test]
+    fn create_and_serialize_section() {
+        let mut new_section = Section::create("Groceries", 2345);
+        let json = serde_json::to_string(&new_section).unwrap();
+        println!("{}", json);
+        assert_eq!(json, r#"{"project_id":2345,"name":"Groceries"}"#);
+
+        new_section.set_order(Some(2));
+        let json = serde_json::to_string(&new_section).unwrap();
+        assert_eq!(json, r#"{"project_id":2345,"order":2,"name":"Groceries"}"#);
+    }
+
+    #[test]
+    fn deserialize_section() {
+        let json = r#"
+            {
+                "id": 7025,
+                "project_id": 2203306141,
+                "order": 1,
+                "name": "Groceries"
+            }
+        "#;
+
+        let section: Section = serde_json::from_str(json).unwrap();
+        assert_eq!(section.name(), "Groceries");
+        assert_eq!(section.id().unwrap(), 7025);
+        assert_eq!(section.project_id(), 2203306141);
+        assert_eq!(section.order().unwrap(), 1);
+
+        let json = serde_json::to_string(&section).unwrap();
+        assert_eq!(json, r#"{"project_id":2203306141,"order":1,"name":"Groceries"}"#);
+        let mut copy = section.clone();
+        assert_eq!(copy, section);
+        copy.set_name("Household");
+        assert_ne!(copy, section);
+    }
+
+    #[test]
+    fn default_section() {
+        let section = Section::default();
+        assert_eq!(section.id(), &None);
+        assert_eq!(section.name(), "");
+        assert_eq!(section.project_id(), 0);
+        assert_eq!(section.order(), &None);
+    }
+}
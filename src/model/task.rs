@@ -2,24 +2,49 @@
 //!
 //! Module containing task-related structures and utilities.
 
-use serde::ser::{Serialize, Serializer, SerializeStruct};
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer, SerializeMap, SerializeStruct};
+use serde_json::{self, Map, Value};
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, NaiveDate, Utc};
+use model::{TaskId, ProjectId, SectionId, LabelId, UserId};
+use model::id::{self, Id};
 
 /// Data model for information about when a task is due.
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Due {
     /// Human defined date in arbitrary format
     string: String,
     /// Date in format YYYY-MM-DD corrected to user’s timezone
+    #[serde(skip_serializing_if = "Option::is_none")]
     date: Option<String>,
     /// Only returned if exact due time set (i.e. it’s not a whole-day task), date and time in
     /// RFC3339 format in UTC
+    #[serde(skip_serializing_if = "Option::is_none")]
     datetime: Option<String>,
     /// Only returned if exact due time set, user’s timezone definition either in tzdata-compatible
     /// format (“Europe/Berlin”) or as a string specifying east of UTC offset as “UTC±HH:MM”
     /// (i.e. “UTC-01:00”)
-    timezone: Option<String>
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timezone: Option<String>,
+    /// Language of the human defined date, used by the server to parse it (defaults to English)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    lang: Option<String>
 }
 
+/// Language codes the server can parse human defined dates in.
+const DUE_LANGS: [&str; 14] = ["en", "da", "pl", "zh", "ko", "de", "pt", "ja", "it", "fr", "sv",
+                               "ru", "es", "nl"];
+
+/// Language code used for human defined dates without a language.
+const DEFAULT_DUE_LANG: &str = "en";
+
 impl Due {
     /// Creates a new instance of information about when a task is due.
     ///
@@ -39,7 +64,8 @@ impl Due {
             string: String::from(string),
             date: None,
             datetime: None,
-            timezone: None
+            timezone: None,
+            lang: None
         }
     }
 
@@ -74,11 +100,59 @@ impl Due {
     /// use todoist_rest::model::task::Due;
     ///
     /// let mut due = Due::create("tomorrow at noon");
-    /// due.set_date("2017-12-25");
+    /// due.set_date_validated("2017-12-25").unwrap();
     /// assert_eq!(due.string(), "2017-12-25");
     /// assert_eq!(due.date(), Some(String::from("2017-12-25")));
     /// assert_eq!(due.datetime(), None);
     /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DueDateError` carrying the value, and leaves the due information unchanged, if
+    /// the value is not a calendar date in YYYY-MM-DD format (i.e. "25-12-2017" or "2017-02-30").
+    pub fn set_date_validated(&mut self, date: &str) -> Result<(), DueDateError> {
+        if !is_valid_date(date) {
+            return Err(DueDateError {
+                value: String::from(date),
+                expected: "a calendar date in YYYY-MM-DD format"
+            });
+        }
+
+        self.string = String::from(date);
+        self.date = Some(String::from(date));
+        self.datetime = None;
+        self.timezone = None;
+        Ok(())
+    }
+
+    /// Sets the exact date when the task is due after checking that it is a calendar date in
+    /// YYYY-MM-DD format.
+    ///
+    /// This is the same as `set_date_validated`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::Due;
+    ///
+    /// let mut due = Due::create("tomorrow at noon");
+    /// assert!(due.try_set_date("2017-12-25").is_ok());
+    /// assert_eq!(due.try_set_date("2017-13-45").unwrap_err().value(), "2017-13-45");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DueError` carrying the value, and leaves the due information unchanged, if the
+    /// value is not a calendar date in YYYY-MM-DD format.
+    pub fn try_set_date(&mut self, date: &str) -> Result<(), DueError> {
+        self.set_date_validated(date)
+    }
+
+    /// Sets the exact date when the task is due (YYYY-MM-DD format) without validating it.
+    ///
+    /// The `string` will also be set to the date. The `datetime` and `timezone` will be set to
+    /// `None`.
+    #[deprecated(note = "use `set_date_validated`, which rejects dates not in YYYY-MM-DD format")]
     pub fn set_date(&mut self, date: &str) {
         self.string = String::from(date);
         self.date = Some(String::from(date));
@@ -89,8 +163,9 @@ impl Due {
     /// Sets the date and time when the task is due
     /// ([RFC3339 format in UTC](https://tools.ietf.org/html/rfc3339#section-5.6)).
     ///
-    /// The `string` will also be set to the datetime. The `datetime` and `timezone` will be set to
-    /// `None`.
+    /// The `string` will also be set to the datetime. The `date` and `timezone` will be set to
+    /// `None`. The value is stored as given; use `try_set_datetime` to reject values the server
+    /// would not accept.
     ///
     /// # Example
     ///
@@ -110,6 +185,68 @@ impl Due {
         self.timezone = None;
     }
 
+    /// Sets the date and time when the task is due after checking that it is in
+    /// [RFC3339 format in UTC](https://tools.ietf.org/html/rfc3339#section-5.6), with either a
+    /// "Z" or a "+00:00" offset.
+    ///
+    /// The `string` will also be set to the datetime. The `date` and `timezone` will be set to
+    /// `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::Due;
+    ///
+    /// let mut due = Due::create("tomorrow at noon");
+    /// due.set_datetime_validated("2017-12-25T12:00:00Z").unwrap();
+    /// assert_eq!(due.datetime(), Some(String::from("2017-12-25T12:00:00Z")));
+    /// assert!(due.set_datetime_validated("2017-12-25T12:00:00+05:30").is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DueDateError` carrying the value, and leaves the due information unchanged, if
+    /// the value is not in RFC3339 format or has an offset from UTC.
+    pub fn set_datetime_validated(&mut self, datetime: &str) -> Result<(), DueDateError> {
+        match rfc3339_offset(datetime) {
+            Some("Z") | Some("z") | Some("+00:00") | Some("-00:00") => (),
+            Some(_) => return Err(DueDateError {
+                value: String::from(datetime),
+                expected: "a date and time in UTC, with a Z or +00:00 offset"
+            }),
+            None => return Err(DueDateError {
+                value: String::from(datetime),
+                expected: "an RFC3339 date and time (i.e. 2017-12-25T12:00:00Z)"
+            })
+        }
+
+        self.set_datetime(datetime);
+        Ok(())
+    }
+
+    /// Sets the date and time when the task is due after checking that it is in RFC3339 format
+    /// in UTC.
+    ///
+    /// This is the same as `set_datetime_validated`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::Due;
+    ///
+    /// let mut due = Due::create("tomorrow at noon");
+    /// assert!(due.try_set_datetime("2017-12-25T12:00:00Z").is_ok());
+    /// assert_eq!(due.try_set_datetime("not-a-date").unwrap_err().value(), "not-a-date");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DueError` carrying the value, and leaves the due information unchanged, if the
+    /// value is not in RFC3339 format or has an offset from UTC.
+    pub fn try_set_datetime(&mut self, datetime: &str) -> Result<(), DueError> {
+        self.set_datetime_validated(datetime)
+    }
+
     /// Gets the human-defined due information.
     ///
     /// # Example
@@ -132,7 +269,7 @@ impl Due {
     /// use todoist_rest::model::task::Due;
     ///
     /// let mut due = Due::create("tomorrow at noon");
-    /// due.set_date("2017-12-25");
+    /// due.set_date_validated("2017-12-25").unwrap();
     /// assert_eq!(due.date(), Some(String::from("2017-12-25")));
     /// ```
     pub fn date(&self) -> Option<String> {
@@ -153,284 +290,929 @@ impl Due {
     pub fn datetime(&self) -> Option<String> {
         self.datetime.clone()
     }
-}
-
-/// Data model for a task.
-#[derive(Deserialize, Debug)]
-pub struct Task {
-    /// Task identifier
-    id: Option<u32>,
-    /// The task's project identifier (read-only)
-    project_id: Option<u32>,
-    /// The task content
-    content: String,
-    /// Flag to mark completed tasks
-    completed: bool,
-    /// Array of label identifiers associated with the task
-    label_ids: Vec<u32>,
-    /// Position of the task within the project (read-only)
-    order: Option<u32>,
-    /// Task indentation level from 1 to 5 (read-only)
-    indent: Option<u32>,
-    /// Task priority from 1 (normal) to 4 (urgent)
-    priority: u32,
-    /// Object representing the task due date/time
-    due: Option<Due>,
-    /// URL to access this task in Todoist web interface
-    url: Option<String>,
-    /// Number of task comments
-    comment_count: Option<u32>
-}
 
-impl Task {
-    /// Creates a new task.
+    /// Gets the date when the task is due as a `chrono::NaiveDate`.
+    ///
+    /// Returns `None` if there is no date or it is not a calendar date in YYYY-MM-DD format.
     ///
     /// # Example
     ///
     /// ```
-    /// use todoist_rest::model::task::Task;
+    /// # extern crate chrono;
+    /// # extern crate todoist_rest;
+    /// use chrono::NaiveDate;
+    /// use todoist_rest::model::task::Due;
     ///
-    /// let task = Task::create("Test Task");
-    /// assert_eq!(task.content(), "Test Task");
+    /// # fn main() {
+    /// let mut due = Due::create("tomorrow at noon");
+    /// due.set_date_validated("2017-12-25").unwrap();
+    /// assert_eq!(due.as_naive_date(), NaiveDate::from_ymd_opt(2017, 12, 25));
+    /// # }
     /// ```
-    pub fn create(content: &str) -> Task {
-        Task {
-            id: None,
-            project_id: None,
-            content: String::from(content),
-            completed: false,
-            label_ids: vec![],
-            order: None,
-            indent: None,
-            priority: 1,
-            due: None,
-            url: None,
-            comment_count: None
-        }
+    #[cfg(feature = "chrono")]
+    pub fn as_naive_date(&self) -> Option<NaiveDate> {
+        self.date.as_ref().and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
     }
 
-    /// Sets the information about when the task is due.
+    /// Gets the date and time when the task is due as a `chrono::DateTime` in UTC.
+    ///
+    /// Returns `None` if there is no date and time or it is not in RFC3339 format.
     ///
     /// # Example
     ///
     /// ```
-    /// use todoist_rest::model::task::{Task, Due};
+    /// # extern crate chrono;
+    /// # extern crate todoist_rest;
+    /// use chrono::{TimeZone, Utc};
+    /// use todoist_rest::model::task::Due;
     ///
+    /// # fn main() {
     /// let mut due = Due::create("tomorrow at noon");
-    /// due.set_date("2017-12-25");
-    /// let mut task = Task::create("Test Task");
-    /// task.set_due(Some(due));
-    /// assert_eq!(task.due().unwrap().date(), Some(String::from("2017-12-25")));
+    /// due.set_datetime("2017-12-25T12:00:00Z");
+    /// assert_eq!(due.as_datetime(), Utc.with_ymd_and_hms(2017, 12, 25, 12, 0, 0).single());
+    /// # }
     /// ```
-    pub fn set_due(&mut self, due: Option<Due>) {
-        self.due = due;
+    #[cfg(feature = "chrono")]
+    pub fn as_datetime(&self) -> Option<DateTime<Utc>> {
+        self.datetime.as_ref()
+            .and_then(|datetime| DateTime::parse_from_rfc3339(datetime).ok())
+            .map(|datetime| datetime.with_timezone(&Utc))
     }
 
-    /// Sets the priority for the task from 1 (normal) to 4 (urgent).
+    /// Sets the exact date when the task is due from a `chrono::NaiveDate`.
+    ///
+    /// The `string` and `date` will be set to the date in YYYY-MM-DD format. The `datetime` and
+    /// `timezone` will be set to `None`.
     ///
     /// # Example
     ///
     /// ```
-    /// use todoist_rest::model::task::Task;
+    /// # extern crate chrono;
+    /// # extern crate todoist_rest;
+    /// use chrono::NaiveDate;
+    /// use todoist_rest::model::task::Due;
     ///
-    /// let mut task = Task::create("Test Task");
-    /// task.set_priority(3);
-    /// assert_eq!(task.priority(), 3);
+    /// # fn main() {
+    /// let mut due = Due::create("tomorrow at noon");
+    /// due.set_naive_date(NaiveDate::from_ymd_opt(2017, 12, 25).unwrap());
+    /// assert_eq!(due.date(), Some(String::from("2017-12-25")));
+    /// # }
     /// ```
-    ///
-    /// # Panics
-    ///
-    /// This method will panic if the provided value is not in the range of 1 to 4.
-    pub fn set_priority(&mut self, priority: u32) {
-        match priority {
-            1...4 => self.priority = priority,
-            _ => panic!("The priority must be a value from 1 and 4.")
-        };
+    #[cfg(feature = "chrono")]
+    pub fn set_naive_date(&mut self, date: NaiveDate) {
+        let date = date.format("%Y-%m-%d").to_string();
+        self.string = date.clone();
+        self.date = Some(date);
+        self.datetime = None;
+        self.timezone = None;
     }
 
-    /// Removes the association of a label from the task.
+    /// Sets the date and time when the task is due from a `chrono::DateTime` in UTC.
+    ///
+    /// The `string` and `datetime` will be set to the date and time in RFC3339 format. The `date`
+    /// and `timezone` will be set to `None`.
     ///
     /// # Example
     ///
     /// ```
-    /// use todoist_rest::model::task::Task;
+    /// # extern crate chrono;
+    /// # extern crate todoist_rest;
+    /// use chrono::{TimeZone, Utc};
+    /// use todoist_rest::model::task::Due;
     ///
-    /// let mut task = Task::create("Test Task");
-    /// task.add_label_id(10);
-    /// task.add_label_id(4);
-    /// task.add_label_id(1);
-    /// assert_eq!(task.label_ids(), [10, 4, 1]);
-    /// task.remove_label_id(4);
-    /// assert_eq!(task.label_ids(), [10, 1]);
+    /// # fn main() {
+    /// let mut due = Due::create("tomorrow at noon");
+    /// due.set_utc_datetime(Utc.with_ymd_and_hms(2017, 12, 25, 12, 0, 0).unwrap());
+    /// assert_eq!(due.datetime(), Some(String::from("2017-12-25T12:00:00Z")));
+    /// # }
     /// ```
-    pub fn remove_label_id(&mut self, label_id: u32) {
-        self.label_ids.retain(|&id| id != label_id);
+    #[cfg(feature = "chrono")]
+    pub fn set_utc_datetime(&mut self, datetime: DateTime<Utc>) {
+        self.set_datetime(&datetime.format("%Y-%m-%dT%H:%M:%SZ").to_string());
     }
 
-    /// Associates a label to the task.
+    /// Sets the language the human defined due information is written in, so the server parses
+    /// it correctly (i.e. "de" for "morgen um 9").
     ///
     /// # Example
     ///
     /// ```
-    /// use todoist_rest::model::task::Task;
+    /// use todoist_rest::model::task::Due;
     ///
-    /// let mut task = Task::create("Test Task");
-    /// task.add_label_id(10);
-    /// task.add_label_id(4);
-    /// task.add_label_id(1);
-    /// assert_eq!(task.label_ids(), [10, 4, 1]);
+    /// let mut due = Due::create("morgen um 9");
+    /// due.set_lang("de").unwrap();
+    /// assert_eq!(due.lang(), "de");
+    /// assert_eq!(due.set_lang("xx").unwrap_err().value(), "xx");
+    /// assert_eq!(due.lang(), "de");
     /// ```
-    pub fn add_label_id(&mut self, label_id: u32) {
-        self.label_ids.push(label_id);
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DueLangError` carrying the value if it is not one of the language codes
+    /// supported by Todoist: en, da, pl, zh, ko, de, pt, ja, it, fr, sv, ru, es and nl.
+    pub fn set_lang(&mut self, lang: &str) -> Result<(), DueLangError> {
+        if !DUE_LANGS.contains(&lang) {
+            return Err(DueLangError { value: String::from(lang) });
+        }
+
+        self.lang = Some(String::from(lang));
+        Ok(())
     }
 
-    /// Sets the content of the task.
+    /// Gets the language the human defined due information is written in, which is English ("en")
+    /// unless another language was set.
     ///
     /// # Example
     ///
     /// ```
-    /// use todoist_rest::model::task::Task;
+    /// use todoist_rest::model::task::Due;
     ///
-    /// let mut task = Task::create("Test Task");
-    /// task.set_content("New Task Name");
-    /// assert_eq!(task.content(), "New Task Name");
+    /// let due = Due::create("tomorrow at noon");
+    /// assert_eq!(due.lang(), "en");
     /// ```
-    pub fn set_content(&mut self, content: &str) {
-        self.content = String::from(content);
+    pub fn lang(&self) -> &str {
+        self.lang.as_deref().unwrap_or(DEFAULT_DUE_LANG)
     }
 
-    /// Sets whether or not the task is completed.
+    /// Gets whether the task is past due.
+    ///
+    /// A task with an exact due time is overdue once that time has passed. A whole-day task is
+    /// overdue from the day after its date, compared using the current date in UTC. Returns
+    /// `false` if the due information has no date.
+    pub fn is_overdue(&self) -> bool {
+        self.is_overdue_at(SystemTime::now())
+    }
+
+    /// Gets whether the task is past due at the given point in time.
     ///
     /// # Example
     ///
     /// ```
-    /// use todoist_rest::model::task::Task;
+    /// use std::time::{Duration, UNIX_EPOCH};
+    /// use todoist_rest::model::task::Due;
     ///
-    /// let mut task = Task::create("Test Task");
-    /// task.set_completed(true);
-    /// assert!(task.completed());
+    /// let mut due = Due::create("tomorrow at noon");
+    /// due.set_datetime("2017-12-25T12:00:00Z");
+    /// let now = UNIX_EPOCH + Duration::from_secs(1_514_203_200); // 2017-12-25T12:00:00Z
+    /// assert!(!due.is_overdue_at(now));
+    /// assert!(due.is_overdue_at(now + Duration::from_secs(1)));
     /// ```
-    pub fn set_completed(&mut self, completed: bool) {
-        self.completed = completed;
+    pub fn is_overdue_at(&self, now: SystemTime) -> bool {
+        self.days_overdue_at(now).is_some()
     }
 
-    /// Gets the task identifier.
-    ///
-    /// # Example
+    /// Gets the number of days the task is past due at the given point in time, or `None` if it
+    /// is not overdue.
     ///
-    /// ```
-    /// use todoist_rest::model::task::Task;
+    /// A whole-day task is one day overdue on the day after its date. A task with an exact due
+    /// time is one day overdue from that time on, and another day for every further 24 hours.
+    pub(crate) fn days_overdue_at(&self, now: SystemTime) -> Option<u32> {
+        let now = unix_seconds(now);
+
+        let days = match self.datetime.as_ref().and_then(|datetime| datetime_seconds(datetime)) {
+            Some(seconds) => (now - seconds + 86_399).div_euclid(86_400),
+            None => now.div_euclid(86_400) - self.date.as_ref().and_then(|date| date_days(date))?
+        };
+
+        if days > 0 {
+            Some(u32::try_from(days).unwrap_or(u32::MAX))
+        } else {
+            None
+        }
+    }
+
+    /// Gets whether the task is due today, compared using the current date in UTC.
     ///
-    /// let task = Task::create("Test Task");
-    /// assert_eq!(task.id(), &None);
-    /// ```
-    pub fn id(&self) -> &Option<u32> {
-        &self.id
+    /// Returns `false` if the due information has no date.
+    pub fn is_today(&self) -> bool {
+        self.is_today_at(SystemTime::now())
     }
 
-    /// Gets the identifier for the project that task associated with.
+    /// Gets whether the task is due on the day (in UTC) of the given point in time.
+    ///
+    /// The date of the task is used if there is one, or else the day of its exact due time in UTC.
     ///
     /// # Example
     ///
     /// ```
-    /// use todoist_rest::model::task::Task;
+    /// use std::time::{Duration, UNIX_EPOCH};
+    /// use todoist_rest::model::task::Due;
     ///
-    /// let task = Task::create("Test Task");
-    /// assert_eq!(task.project_id(), &None);
+    /// let mut due = Due::create("Christmas");
+    /// due.set_date_validated("2017-12-25").unwrap();
+    /// let now = UNIX_EPOCH + Duration::from_secs(1_514_203_200); // 2017-12-25T12:00:00Z
+    /// assert!(due.is_today_at(now));
+    /// assert!(!due.is_today_at(now + Duration::from_secs(43_200)));
     /// ```
-    pub fn project_id(&self) -> &Option<u32> {
-        &self.project_id
+    pub fn is_today_at(&self, now: SystemTime) -> bool {
+        let today = unix_seconds(now).div_euclid(86_400);
+        let days = match self.date {
+            Some(ref date) => date_days(date),
+            None => self.datetime.as_ref()
+                .and_then(|datetime| datetime_seconds(datetime))
+                .map(|seconds| seconds.div_euclid(86_400))
+        };
+
+        days == Some(today)
     }
 
-    /// Gets the task's content.
+    /// Gets the day the task is due (YYYY-MM-DD) and the exact time if there is one, used to sort
+    /// tasks by due date.
+    fn sort_key(&self) -> Option<(&str, Option<&str>)> {
+        let datetime = self.datetime.as_deref();
+
+        match self.date {
+            Some(ref date) => Some((date.as_str(), datetime)),
+            None => datetime.map(|datetime| (datetime.get(..10).unwrap_or(datetime), Some(datetime)))
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Due {
+    /// Creates a due date from its human-defined representation, like `Due::create`.
     ///
     /// # Example
     ///
     /// ```
     /// use todoist_rest::model::task::Task;
     ///
-    /// let task = Task::create("Test Task");
-    /// assert_eq!(task.content(), "Test Task");
+    /// let mut task = Task::create("Test Task");
+    /// task.set_due(Some("tomorrow".into()));
+    /// assert_eq!(task.due().unwrap().string(), "tomorrow");
     /// ```
-    pub fn content(&self) -> &str {
-        &self.content
+    fn from(string: &'a str) -> Due {
+        Due::create(string)
     }
+}
 
-    /// Gets whether the task is completed or not.
+impl From<String> for Due {
+    /// Creates a due date from its human-defined representation, like `Due::create`.
     ///
     /// # Example
     ///
     /// ```
-    /// use todoist_rest::model::task::Task;
+    /// use todoist_rest::model::task::Due;
     ///
-    /// let task = Task::create("Test Task");
-    /// assert_eq!(task.completed(), false);
+    /// let due = Due::from(String::from("every monday"));
+    /// assert_eq!(due.string(), "every monday");
     /// ```
-    pub fn completed(&self) -> bool {
-        self.completed
+    fn from(string: String) -> Due {
+        Due { string, ..Due::default() }
     }
+}
 
-    /// Gets the identifiers of the labels associated with the task.
+impl FromStr for Due {
+    type Err = DueDateParseError;
+
+    /// Creates a due date from its human-defined representation, like `Due::create`.
     ///
     /// # Example
     ///
     /// ```
-    /// use todoist_rest::model::task::Task;
+    /// use todoist_rest::model::task::Due;
     ///
-    /// let mut task = Task::create("Test Task");
-    /// task.add_label_id(10);
-    /// task.add_label_id(4);
-    /// task.add_label_id(1);
-    /// assert_eq!(task.label_ids(), [10, 4, 1]);
+    /// let due: Due = "tomorrow at noon".parse().unwrap();
+    /// assert_eq!(due.string(), "tomorrow at noon");
+    /// assert!("  ".parse::<Due>().is_err());
     /// ```
-    pub fn label_ids(&self) -> Vec<u32>{
-        self.label_ids.clone()
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DueDateParseError` if the string is empty or only whitespace.
+    fn from_str(string: &str) -> Result<Due, DueDateParseError> {
+        if string.trim().is_empty() {
+            return Err(DueDateParseError { value: String::from(string) });
+        }
+
+        Ok(Due::create(string))
     }
+}
 
-    /// Gets the order of the task with a list of tasks.
+/// The priority of a task.
+///
+/// The Todoist API represents priorities as the integers 1 (normal) to 4 (urgent), which is how
+/// this type is serialized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Priority {
+    /// Normal priority (1)
+    Normal,
+    /// Medium priority (2)
+    Medium,
+    /// High priority (3)
+    High,
+    /// Urgent priority (4)
+    Urgent
+}
+
+impl Priority {
+    /// Converts an API priority value, saturating values outside of the range 1 to 4.
+    ///
+    /// Use `Priority::try_from` to reject out-of-range values instead.
     ///
     /// # Example
     ///
     /// ```
-    /// use todoist_rest::model::task::Task;
+    /// use todoist_rest::model::task::Priority;
     ///
-    /// let task = Task::create("Test Task");
-    /// assert_eq!(task.order(), &None);
+    /// assert_eq!(Priority::saturating_from(3), Priority::High);
+    /// assert_eq!(Priority::saturating_from(0), Priority::Normal);
+    /// assert_eq!(Priority::saturating_from(7), Priority::Urgent);
     /// ```
-    pub fn order(&self) -> &Option<u32>{
-        &self.order
+    pub fn saturating_from(value: u32) -> Priority {
+        match value {
+            0 | 1 => Priority::Normal,
+            2 => Priority::Medium,
+            3 => Priority::High,
+            _ => Priority::Urgent
+        }
     }
 
-    /// Gets the indentation level for the task in a list of tasks.
+    /// Converts a priority as displayed in the Todoist apps, where "p1" is urgent and "p4" is
+    /// normal.
+    ///
+    /// The apps number priorities in the reverse order of the API, so UI priority 1 is API
+    /// priority 4 and UI priority 4 is API priority 1.
     ///
     /// # Example
     ///
     /// ```
-    /// use todoist_rest::model::task::Task;
+    /// use todoist_rest::model::task::Priority;
     ///
-    /// let task = Task::create("Test Task");
-    /// assert_eq!(task.indent(), &None);
+    /// assert_eq!(Priority::from_ui_priority(1).unwrap(), Priority::Urgent);
+    /// assert_eq!(Priority::from_ui_priority(4).unwrap(), Priority::Normal);
+    /// assert!(Priority::from_ui_priority(0).is_err());
     /// ```
-    pub fn indent(&self) -> &Option<u32>{
-        &self.indent
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PriorityError` carrying the value if it is not in the range of 1 to 4.
+    pub fn from_ui_priority(ui_priority: u8) -> Result<Priority, PriorityError> {
+        match ui_priority {
+            1..=4 => Ok(Priority::saturating_from(5 - u32::from(ui_priority))),
+            _ => Err(PriorityError { value: u32::from(ui_priority) })
+        }
     }
 
-    /// Gets the priority of the task from 1 to 4.
+    /// Gets the priority as displayed in the Todoist apps, where "p1" is urgent and "p4" is
+    /// normal.
     ///
     /// # Example
     ///
     /// ```
-    /// use todoist_rest::model::task::Task;
+    /// use todoist_rest::model::task::Priority;
     ///
-    /// let mut task = Task::create("Test Task");
-    /// task.set_priority(3);
-    /// assert_eq!(task.priority(), 3);
+    /// assert_eq!(Priority::Urgent.ui_priority(), 1);
+    /// assert_eq!(Priority::Normal.ui_priority(), 4);
     /// ```
-    pub fn priority(&self) -> u32{
-        self.priority
+    pub fn ui_priority(&self) -> u8 {
+        match *self {
+            Priority::Normal => 4,
+            Priority::Medium => 3,
+            Priority::High => 2,
+            Priority::Urgent => 1
+        }
     }
+}
 
-    /// Gets information about when the task is due.
+impl Default for Priority {
+    /// Gets the priority of new tasks, which is `Priority::Normal`.
+    fn default() -> Priority {
+        Priority::Normal
+    }
+}
+
+impl TryFrom<u32> for Priority {
+    type Error = PriorityError;
+
+    /// Converts an API priority value, failing for values outside of the range 1 to 4.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use todoist_rest::model::task::Priority;
+    ///
+    /// assert_eq!(Priority::try_from(4).unwrap(), Priority::Urgent);
+    /// assert_eq!(Priority::try_from(5).unwrap_err().value(), 5);
+    /// ```
+    fn try_from(value: u32) -> Result<Priority, PriorityError> {
+        match value {
+            1..=4 => Ok(Priority::saturating_from(value)),
+            _ => Err(PriorityError { value })
+        }
+    }
+}
+
+impl From<Priority> for u32 {
+    /// Converts the priority to its API value from 1 (normal) to 4 (urgent).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::Priority;
+    ///
+    /// assert_eq!(u32::from(Priority::Urgent), 4);
+    /// ```
+    fn from(priority: Priority) -> u32 {
+        match priority {
+            Priority::Normal => 1,
+            Priority::Medium => 2,
+            Priority::High => 3,
+            Priority::Urgent => 4
+        }
+    }
+}
+
+impl Serialize for Priority {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where
+        S: Serializer {
+        serializer.serialize_u32(u32::from(*self))
+    }
+}
+
+impl<'de> Deserialize<'de> for Priority {
+    fn deserialize<D>(deserializer: D) -> Result<Priority, D::Error> where
+        D: Deserializer<'de> {
+        let value = u32::deserialize(deserializer)?;
+        Priority::try_from(value).map_err(de::Error::custom)
+    }
+}
+
+/// Error returned when a value is not a valid task priority.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PriorityError {
+    value: u32
+}
+
+impl PriorityError {
+    /// Gets the value that was rejected as a priority.
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+}
+
+impl fmt::Display for PriorityError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid priority {}, expected a value from 1 to 4", self.value)
+    }
+}
+
+impl Error for PriorityError {}
+
+/// Error returned when a value is not a valid task indentation level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndentError {
+    value: u32
+}
+
+impl IndentError {
+    /// Gets the value that was rejected as an indentation level.
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+}
+
+impl fmt::Display for IndentError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid indent {}, expected a value from 1 to 5", self.value)
+    }
+}
+
+impl Error for IndentError {}
+
+/// Error returned when a `TaskBuilder` is given invalid values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskBuildError {
+    /// The task content is empty or only whitespace
+    EmptyContent,
+    /// The indentation level is not in the range of 1 to 5
+    Indent(IndentError)
+}
+
+impl fmt::Display for TaskBuildError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TaskBuildError::EmptyContent => write!(f, "task content is empty"),
+            TaskBuildError::Indent(ref error) => error.fmt(f)
+        }
+    }
+}
+
+impl Error for TaskBuildError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            TaskBuildError::EmptyContent => None,
+            TaskBuildError::Indent(ref error) => Some(error)
+        }
+    }
+}
+
+impl From<IndentError> for TaskBuildError {
+    fn from(error: IndentError) -> TaskBuildError {
+        TaskBuildError::Indent(error)
+    }
+}
+
+/// Error returned when a value is not a language code supported for due dates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DueLangError {
+    value: String
+}
+
+impl DueLangError {
+    /// Gets the value that was rejected as a language code.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl fmt::Display for DueLangError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unsupported due date language \"{}\", expected one of {}", self.value,
+               DUE_LANGS.join(", "))
+    }
+}
+
+impl Error for DueLangError {}
+
+/// Error returned when a value is not a valid due date or due date and time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DueDateError {
+    value: String,
+    expected: &'static str
+}
+
+impl DueDateError {
+    /// Gets the value that was rejected as a due date.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl fmt::Display for DueDateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid due date \"{}\", expected {}", self.value, self.expected)
+    }
+}
+
+impl Error for DueDateError {}
+
+/// Error returned when a string cannot be parsed as a due date.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DueDateParseError {
+    value: String
+}
+
+impl DueDateParseError {
+    /// Gets the string that was rejected as a due date.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl fmt::Display for DueDateParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid due date \"{}\", expected a non-empty string", self.value)
+    }
+}
+
+impl Error for DueDateParseError {}
+
+/// Error returned when due information is invalid.
+pub type DueError = DueDateError;
+
+/// Parses a value consisting only of ASCII digits.
+fn parse_digits(value: Option<&str>) -> Option<u32> {
+    match value {
+        Some(value) if !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit()) => {
+            value.parse().ok()
+        },
+        _ => None
+    }
+}
+
+/// Checks that a value is a calendar date in YYYY-MM-DD format.
+fn is_valid_date(date: &str) -> bool {
+    let bytes = date.as_bytes();
+
+    if bytes.len() != 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+        return false;
+    }
+
+    let (year, month, day) = match (parse_digits(date.get(0..4)), parse_digits(date.get(5..7)),
+                                    parse_digits(date.get(8..10))) {
+        (Some(year), Some(month), Some(day)) => (year, month, day),
+        _ => return false
+    };
+
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        _ => return false
+    };
+
+    day >= 1 && day <= days_in_month
+}
+
+/// Checks that a value is a time in HH:MM or HH:MM:SS format, allowing a leap second.
+fn is_valid_time(time: &str) -> bool {
+    let bytes = time.as_bytes();
+
+    if (bytes.len() != 5 && bytes.len() != 8) || bytes[2] != b':' {
+        return false;
+    }
+
+    let seconds = if bytes.len() == 8 {
+        if bytes[5] != b':' {
+            return false;
+        }
+
+        parse_digits(time.get(6..8))
+    } else {
+        Some(0)
+    };
+
+    match (parse_digits(time.get(0..2)), parse_digits(time.get(3..5)), seconds) {
+        (Some(hours), Some(minutes), Some(seconds)) => hours < 24 && minutes < 60 && seconds <= 60,
+        _ => false
+    }
+}
+
+/// Gets the UTC offset of an RFC3339 date and time ("Z" or "±HH:MM"), or `None` if the value is
+/// not in RFC3339 format.
+fn rfc3339_offset(datetime: &str) -> Option<&str> {
+    if !datetime.get(..10).is_some_and(is_valid_date) {
+        return None;
+    }
+
+    match datetime.get(10..11) {
+        Some("T") | Some("t") => (),
+        _ => return None
+    }
+
+    if !datetime.get(11..19).is_some_and(is_valid_time) {
+        return None;
+    }
+
+    let mut offset = &datetime[19..];
+
+    if offset.starts_with('.') {
+        let fraction = offset[1..].bytes().take_while(u8::is_ascii_digit).count();
+
+        if fraction == 0 {
+            return None;
+        }
+
+        offset = &offset[1 + fraction..];
+    }
+
+    match offset.get(..1) {
+        Some("Z") | Some("z") if offset.len() == 1 => Some(offset),
+        Some("+") | Some("-") if offset.get(1..).is_some_and(is_valid_time) &&
+            offset.len() == 6 => Some(offset),
+        _ => None
+    }
+}
+
+/// Gets the number of days from 1970-01-01 to a date in the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = i64::from(month);
+    let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 +
+        i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Gets the number of days from 1970-01-01 to a date in YYYY-MM-DD format.
+fn date_days(date: &str) -> Option<i64> {
+    if !is_valid_date(date) {
+        return None;
+    }
+
+    match (parse_digits(date.get(0..4)), parse_digits(date.get(5..7)),
+           parse_digits(date.get(8..10))) {
+        (Some(year), Some(month), Some(day)) => Some(days_from_civil(i64::from(year), month, day)),
+        _ => None
+    }
+}
+
+/// Gets the number of seconds from 1970-01-01T00:00:00Z to an RFC3339 date and time.
+fn datetime_seconds(datetime: &str) -> Option<i64> {
+    let offset = rfc3339_offset(datetime)?;
+    let days = date_days(&datetime[..10])?;
+    let time = |range| parse_digits(datetime.get(range)).map(i64::from);
+    let seconds = days * 86_400 + time(11..13)? * 3_600 + time(14..16)? * 60 + time(17..19)?;
+
+    let offset_seconds = match offset.get(..1) {
+        Some(sign @ "+") | Some(sign @ "-") => {
+            let minutes = parse_digits(offset.get(1..3))? * 60 + parse_digits(offset.get(4..6))?;
+            let minutes = i64::from(minutes);
+            if sign == "+" { minutes * 60 } else { -minutes * 60 }
+        },
+        _ => 0
+    };
+
+    Some(seconds - offset_seconds)
+}
+
+/// Gets the number of seconds from 1970-01-01T00:00:00Z to a point in time.
+fn unix_seconds(time: SystemTime) -> i64 {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(duration) => duration.as_secs() as i64,
+        Err(error) => -(error.duration().as_secs() as i64)
+    }
+}
+
+/// Flags for the task fields that have been modified through a setter.
+const CHANGED_CONTENT: u8 = 1;
+const CHANGED_PARENT_ID: u8 = 1 << 1;
+const CHANGED_INDENT: u8 = 1 << 2;
+const CHANGED_LABEL_IDS: u8 = 1 << 3;
+const CHANGED_PRIORITY: u8 = 1 << 4;
+const CHANGED_DUE: u8 = 1 << 5;
+const CHANGED_ASSIGNEE: u8 = 1 << 6;
+
+/// Request body for updating a task, holding the fields the update endpoint accepts.
+#[derive(Serialize)]
+struct TaskUpdate<'a> {
+    content: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    label_ids: Option<Vec<Id>>,
+    priority: Priority,
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    due: Option<DueFields<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none", with = "id::option")]
+    assignee: Option<UserId>
+}
+
+/// The request fields of a due date, serialized as a map to be flattened into a request body.
+struct DueFields<'a>(&'a Due);
+
+impl<'a> Serialize for DueFields<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where
+        S: Serializer {
+        let fields = due_fields(self.0);
+        let mut map = serializer.serialize_map(Some(fields.len()))?;
+
+        for (name, value) in fields {
+            map.serialize_entry(name, value)?;
+        }
+
+        map.end()
+    }
+}
+
+/// Gets the request fields describing a due date: the exact due time if there is one, or else
+/// the date, or else the human-defined string along with its language.
+fn due_fields(due: &Due) -> Vec<(&'static str, &str)> {
+    if let Some(ref datetime) = due.datetime {
+        vec![("due_datetime", datetime)]
+    } else if let Some(ref date) = due.date {
+        vec![("due_date", date)]
+    } else {
+        vec![("due_string", due.string()), ("due_lang", due.lang())]
+    }
+}
+
+/// Data model for a task.
+///
+/// Serializing a task gives the body for creating it. Use `Task::to_update_json` for the body of
+/// an update, which accepts fewer fields.
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct Task {
+    /// Task identifier
+    #[serde(default, deserialize_with = "id::option::deserialize")]
+    id: Option<TaskId>,
+    /// The task's project identifier
+    #[serde(default, deserialize_with = "id::option::deserialize")]
+    project_id: Option<ProjectId>,
+    /// The task's section identifier (read-only)
+    #[serde(default, deserialize_with = "id::option::deserialize")]
+    section_id: Option<SectionId>,
+    /// Identifier of the parent task for sub-tasks
+    #[serde(default, deserialize_with = "id::option::deserialize")]
+    parent_id: Option<TaskId>,
+    /// The task content
+    content: String,
+    /// Flag to mark completed tasks
+    #[serde(default, alias = "is_completed")]
+    completed: bool,
+    /// Array of label identifiers associated with the task
+    #[serde(default, deserialize_with = "id::vec::deserialize")]
+    label_ids: Vec<LabelId>,
+    /// Names of the labels associated with the task, as returned by the quick add endpoint
+    /// (read-only)
+    #[serde(default)]
+    labels: Vec<String>,
+    /// Position of the task within the project (may be overridden by the server)
+    order: Option<u32>,
+    /// Task indentation level from 1 to 5 (may be overridden by the server)
+    indent: Option<u32>,
+    /// Task priority from normal to urgent
+    #[serde(default)]
+    priority: Priority,
+    /// Object representing the task due date/time
+    due: Option<Due>,
+    /// URL to access this task in Todoist web interface
+    url: Option<String>,
+    /// Number of task comments
+    comment_count: Option<u32>,
+    /// Identifier of the user the task is assigned to in a shared project
+    #[serde(default, alias = "assignee_id", deserialize_with = "id::option::deserialize")]
+    assignee: Option<UserId>,
+    /// Identifier of the user who assigned the task (read-only)
+    #[serde(default, alias = "assigner_id", deserialize_with = "id::option::deserialize")]
+    assigner: Option<UserId>,
+    /// Date and time when the task was created in RFC3339 format in UTC (read-only)
+    #[serde(default, alias = "created_at")]
+    created: Option<String>,
+    /// Fields modified since the task was created, deserialized or its changes were reset
+    #[serde(skip)]
+    changes: u8
+}
+
+impl Task {
+    /// Creates a new task.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::Task;
+    ///
+    /// let task = Task::create("Test Task");
+    /// assert_eq!(task.content(), "Test Task");
+    /// ```
+    pub fn create(content: &str) -> Task {
+        Task {
+            id: None,
+            project_id: None,
+            section_id: None,
+            parent_id: None,
+            content: String::from(content),
+            completed: false,
+            label_ids: vec![],
+            labels: vec![],
+            order: None,
+            indent: None,
+            priority: Priority::Normal,
+            due: None,
+            url: None,
+            comment_count: None,
+            assignee: None,
+            assigner: None,
+            created: None,
+            changes: 0
+        }
+    }
+
+    /// Creates a new task that is due at the given time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::{Task, Due};
+    ///
+    /// let task = Task::create_with_due("Test Task", Due::create("tomorrow"));
+    /// assert_eq!(task.content(), "Test Task");
+    /// assert_eq!(task.due().unwrap().string(), "tomorrow");
+    /// ```
+    pub fn create_with_due(content: &str, due: Due) -> Task {
+        let mut task = Task::create(content);
+        task.set_due(Some(due));
+        task
+    }
+
+    /// Starts building a new task with the given content.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::{Task, Due, Priority};
+    ///
+    /// let task = Task::builder("Buy milk")
+    ///     .project_id(123)
+    ///     .priority(Priority::High)
+    ///     .due(Due::create("today"))
+    ///     .label_id(4)
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(task.project_id(), &Some(123));
+    /// assert_eq!(task.priority(), Priority::High);
+    /// assert_eq!(task.label_ids(), [4]);
+    /// ```
+    pub fn builder(content: &str) -> TaskBuilder {
+        TaskBuilder {
+            task: Task::create(content),
+            indent: None
+        }
+    }
+
+    /// Sets the information about when the task is due.
     ///
     /// # Example
     ///
@@ -438,189 +1220,2476 @@ impl Task {
     /// use todoist_rest::model::task::{Task, Due};
     ///
     /// let mut due = Due::create("tomorrow at noon");
-    /// due.set_date("2017-12-25");
+    /// due.set_date_validated("2017-12-25").unwrap();
     /// let mut task = Task::create("Test Task");
     /// task.set_due(Some(due));
     /// assert_eq!(task.due().unwrap().date(), Some(String::from("2017-12-25")));
     /// ```
-    pub fn due(&self) -> Option<Due> {
-        self.due.clone()
+    pub fn set_due(&mut self, due: Option<Due>) {
+        self.due = due;
+        self.changes |= CHANGED_DUE;
+    }
+
+    /// Sets the priority for the task.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::{Task, Priority};
+    ///
+    /// let mut task = Task::create("Test Task");
+    /// task.set_priority(Priority::High);
+    /// assert_eq!(task.priority(), Priority::High);
+    /// ```
+    pub fn set_priority(&mut self, priority: Priority) {
+        self.priority = priority;
+        self.changes |= CHANGED_PRIORITY;
+    }
+
+    /// Sets the priority for the task from an API value from 1 (normal) to 4 (urgent).
+    ///
+    /// The priority is left unchanged when the value is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::{Task, Priority};
+    ///
+    /// let mut task = Task::create("Test Task");
+    /// assert!(task.try_set_priority(3).is_ok());
+    /// assert_eq!(task.priority(), Priority::High);
+    /// assert_eq!(task.try_set_priority(7).unwrap_err().value(), 7);
+    /// assert_eq!(task.priority(), Priority::High);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PriorityError` carrying the value if it is not in the range of 1 to 4.
+    pub fn try_set_priority(&mut self, priority: u32) -> Result<(), PriorityError> {
+        self.set_priority(Priority::try_from(priority)?);
+        Ok(())
+    }
+
+    /// Sets the priority for the task as displayed in the Todoist apps, where "p1" is urgent and
+    /// "p4" is normal.
+    ///
+    /// The priority is still sent to the API using the API numbering, so UI priority 1 is
+    /// serialized as priority 4. The priority is left unchanged when the value is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::{Task, Priority};
+    ///
+    /// let mut task = Task::create("Test Task");
+    /// task.set_ui_priority(1).unwrap();
+    /// assert_eq!(task.priority(), Priority::Urgent);
+    /// assert_eq!(task.ui_priority(), 1);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PriorityError` carrying the value if it is not in the range of 1 to 4.
+    pub fn set_ui_priority(&mut self, ui_priority: u8) -> Result<(), PriorityError> {
+        self.set_priority(Priority::from_ui_priority(ui_priority)?);
+        Ok(())
+    }
+
+    /// Removes the association of a label from the task.
+    ///
+    /// Returns whether the label was associated with the task. The order of the remaining labels
+    /// is preserved.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::Task;
+    ///
+    /// let mut task = Task::create("Test Task");
+    /// task.add_label_id(10);
+    /// task.add_label_id(4);
+    /// task.add_label_id(1);
+    /// assert_eq!(task.label_ids(), [10, 4, 1]);
+    /// assert!(task.remove_label_id(4));
+    /// assert_eq!(task.label_ids(), [10, 1]);
+    /// assert!(!task.remove_label_id(4));
+    /// ```
+    pub fn remove_label_id(&mut self, label_id: LabelId) -> bool {
+        let len = self.label_ids.len();
+        self.label_ids.retain(|&id| id != label_id);
+
+        if self.label_ids.len() == len {
+            return false;
+        }
+
+        self.changes |= CHANGED_LABEL_IDS;
+        true
+    }
+
+    /// Associates a label to the task.
+    ///
+    /// Returns whether the label was newly added. A label that is already associated with the
+    /// task is not added again.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::Task;
+    ///
+    /// let mut task = Task::create("Test Task");
+    /// assert!(task.add_label_id(10));
+    /// assert!(task.add_label_id(4));
+    /// assert!(task.add_label_id(1));
+    /// assert!(!task.add_label_id(10));
+    /// assert_eq!(task.label_ids(), [10, 4, 1]);
+    /// ```
+    pub fn add_label_id(&mut self, label_id: LabelId) -> bool {
+        if self.label_ids.contains(&label_id) {
+            return false;
+        }
+
+        self.label_ids.push(label_id);
+        self.changes |= CHANGED_LABEL_IDS;
+        true
+    }
+
+    /// Sets the identifier of the parent task, making this task a sub-task.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::Task;
+    ///
+    /// let mut task = Task::create("Test Task");
+    /// task.set_parent_id(Some(1234));
+    /// assert_eq!(task.parent_id(), &Some(1234));
+    /// ```
+    pub fn set_parent_id(&mut self, parent_id: Option<TaskId>) {
+        self.parent_id = parent_id;
+        self.changes |= CHANGED_PARENT_ID;
+    }
+
+    /// Sets the position of the task within the project, for ordering tasks locally.
+    ///
+    /// The order is read-only on the server, so it is sent neither when the task is created nor
+    /// when it is updated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::Task;
+    ///
+    /// let mut task = Task::create("Test Task");
+    /// task.set_order(Some(3));
+    /// assert_eq!(task.order(), &Some(3));
+    /// ```
+    pub fn set_order(&mut self, order: Option<u32>) {
+        self.order = order;
+    }
+
+    /// Sets the identifier of the project to create the task in, or `None` for the inbox.
+    ///
+    /// The project is only sent when the task is created. Use `TodoistClient::move_task` to move
+    /// an existing task to another project.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::Task;
+    ///
+    /// let mut task = Task::create("Test Task");
+    /// task.set_project_id(Some(2345));
+    /// assert_eq!(task.project_id(), &Some(2345));
+    /// ```
+    pub fn set_project_id(&mut self, project_id: Option<ProjectId>) {
+        self.project_id = project_id;
+    }
+
+    /// Sets the indentation level of the task from 1 to 5.
+    ///
+    /// The server may override the indentation level when the task is saved. The indentation
+    /// level is left unchanged when the value is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::Task;
+    ///
+    /// let mut task = Task::create("Test Task");
+    /// task.set_indent(2).unwrap();
+    /// assert_eq!(task.indent(), &Some(2));
+    /// assert_eq!(task.set_indent(6).unwrap_err().value(), 6);
+    /// assert_eq!(task.indent(), &Some(2));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `IndentError` carrying the value if it is not in the range of 1 to 5.
+    pub fn set_indent(&mut self, indent: u32) -> Result<(), IndentError> {
+        match indent {
+            1..=5 => {
+                self.indent = Some(indent);
+                self.changes |= CHANGED_INDENT;
+                Ok(())
+            },
+            _ => Err(IndentError { value: indent })
+        }
+    }
+
+    /// Replaces the labels associated with the task.
+    ///
+    /// Duplicate identifiers are dropped, keeping the order in which each label first appears.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::Task;
+    ///
+    /// let mut task = Task::create("Test Task");
+    /// task.add_label_id(10);
+    /// task.set_label_ids(vec![7, 7, 2]);
+    /// assert_eq!(task.label_ids(), [7, 2]);
+    /// ```
+    pub fn set_label_ids(&mut self, label_ids: Vec<LabelId>) {
+        self.label_ids.clear();
+        self.changes |= CHANGED_LABEL_IDS;
+
+        for label_id in label_ids {
+            self.add_label_id(label_id);
+        }
+    }
+
+    /// Removes all labels associated with the task.
+    ///
+    /// A task without labels normally leaves them out when serialized, but cleared labels are
+    /// sent as an empty list until the changes are reset, so the server removes them as well.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::Task;
+    ///
+    /// let mut task = Task::create("Test Task");
+    /// task.set_label_ids(vec![10, 4, 1]);
+    /// task.clear_label_ids();
+    /// assert!(task.label_ids().is_empty());
+    /// ```
+    pub fn clear_label_ids(&mut self) {
+        self.label_ids.clear();
+        self.changes |= CHANGED_LABEL_IDS;
+    }
+
+    /// Sets the content of the task.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::Task;
+    ///
+    /// let mut task = Task::create("Test Task");
+    /// task.set_content("New Task Name");
+    /// assert_eq!(task.content(), "New Task Name");
+    /// ```
+    pub fn set_content(&mut self, content: &str) {
+        self.content = String::from(content);
+        self.changes |= CHANGED_CONTENT;
+    }
+
+    /// Sets whether or not the task is completed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::Task;
+    ///
+    /// let mut task = Task::create("Test Task");
+    /// task.set_completed(true);
+    /// assert!(task.completed());
+    /// ```
+    pub fn set_completed(&mut self, completed: bool) {
+        self.completed = completed;
+    }
+
+    /// Sets the identifier of the user the task is assigned to in a shared project, or `None` to
+    /// unassign it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::Task;
+    ///
+    /// let mut task = Task::create("Test Task");
+    /// task.set_assignee(Some(55));
+    /// assert_eq!(task.assignee(), &Some(55));
+    /// ```
+    pub fn set_assignee(&mut self, assignee: Option<UserId>) {
+        self.assignee = assignee;
+        self.changes |= CHANGED_ASSIGNEE;
+    }
+
+    /// Gets the body for updating the task, containing only the fields modified through a setter
+    /// since the task was created, deserialized or its changes were last reset.
+    ///
+    /// Sending only the modified fields avoids overwriting changes made to the other fields by
+    /// another client. A due date that was removed is sent as the due string "no date". The
+    /// completion state is not included, since tasks are completed through their own endpoints.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::{Task, Priority};
+    ///
+    /// let mut task = Task::create("Test Task");
+    /// task.set_priority(Priority::High);
+    /// assert_eq!(task.update_payload().to_string(), r#"{"priority":3}"#);
+    /// ```
+    pub fn update_payload(&self) -> Value {
+        let mut payload = Map::new();
+
+        if self.changes & CHANGED_CONTENT != 0 {
+            payload.insert(String::from("content"), Value::from(self.content.as_str()));
+        }
+
+        if self.changes & CHANGED_PARENT_ID != 0 {
+            payload.insert(String::from("parent_id"), Value::from(self.parent_id.map(Id)));
+        }
+
+        if self.changes & CHANGED_INDENT != 0 {
+            payload.insert(String::from("indent"), Value::from(self.indent));
+        }
+
+        if self.changes & CHANGED_LABEL_IDS != 0 {
+            payload.insert(String::from("label_ids"), Value::from(self.label_id_values()));
+        }
+
+        if self.changes & CHANGED_PRIORITY != 0 {
+            payload.insert(String::from("priority"), Value::from(u32::from(self.priority)));
+        }
+
+        if self.changes & CHANGED_ASSIGNEE != 0 {
+            payload.insert(String::from("assignee"), Value::from(self.assignee.map(Id)));
+        }
+
+        if self.changes & CHANGED_DUE != 0 {
+            match self.due {
+                Some(ref due) => {
+                    for (name, value) in due_fields(due) {
+                        payload.insert(String::from(name), Value::from(value));
+                    }
+                },
+                None => {
+                    payload.insert(String::from("due_string"), Value::from("no date"));
+                }
+            }
+        }
+
+        Value::Object(payload)
+    }
+
+    /// Clears the record of modified fields, typically after the task was successfully updated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::Task;
+    ///
+    /// let mut task = Task::create("Test Task");
+    /// task.set_content("New Task Name");
+    /// task.reset_changes();
+    /// assert_eq!(task.update_payload().to_string(), "{}");
+    /// ```
+    pub fn reset_changes(&mut self) {
+        self.changes = 0;
+    }
+
+    /// Gets the JSON body for creating the task.
+    ///
+    /// This is the same as serializing the task, and includes the project and parent task.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::Task;
+    ///
+    /// let mut task = Task::create("Test Task");
+    /// task.set_parent_id(Some(1234));
+    /// let json = task.to_create_json().unwrap();
+    /// assert!(json.contains(r#""parent_id":1234"#));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `serde_json::Error` if the task could not be serialized.
+    pub fn to_create_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+
+    /// Gets the JSON body for updating the task with all of the fields the update endpoint
+    /// accepts.
+    ///
+    /// The project and parent task are never included, since they can only be changed by moving
+    /// the task. Neither are the order and indentation level, which are managed by the server.
+    /// Like in the create body, an empty list of labels is only sent if the labels were cleared,
+    /// so that the update does not remove labels the task has on the server.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::Task;
+    ///
+    /// let task = Task::create("Test Task");
+    /// assert_eq!(task.to_update_json().unwrap(), r#"{"content":"Test Task","priority":1}"#);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `serde_json::Error` if the task could not be serialized.
+    pub fn to_update_json(&self) -> Result<String, serde_json::Error> {
+        let update = TaskUpdate {
+            content: &self.content,
+            label_ids: if self.sends_label_ids() { Some(self.label_id_values()) } else { None },
+            priority: self.priority,
+            due: self.due.as_ref().map(DueFields),
+            assignee: self.assignee
+        };
+
+        serde_json::to_string(&update)
+    }
+
+    /// Sets the task identifier, as done by the server when the task is created.
+    #[cfg(feature = "mock")]
+    pub(crate) fn set_id(&mut self, id: TaskId) {
+        self.id = Some(id);
+    }
+
+    /// Sets the identifier of the section the task belongs to.
+    #[cfg(feature = "mock")]
+    pub(crate) fn set_section_id(&mut self, section_id: Option<SectionId>) {
+        self.section_id = section_id;
+    }
+
+    /// Gets whether the labels are serialized, which is when the task has labels or they were
+    /// explicitly changed (i.e. cleared) since the changes were last reset.
+    fn sends_label_ids(&self) -> bool {
+        !self.label_ids.is_empty() || self.changes & CHANGED_LABEL_IDS != 0
+    }
+
+    /// Gets the label identifiers with their JSON representation.
+    fn label_id_values(&self) -> Vec<Id> {
+        self.label_ids.iter().map(|label_id| Id(*label_id)).collect()
+    }
+
+    /// Gets the task identifier.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::Task;
+    ///
+    /// let task = Task::create("Test Task");
+    /// assert_eq!(task.id(), &None);
+    /// ```
+    pub fn id(&self) -> &Option<TaskId> {
+        &self.id
+    }
+
+    /// Gets the identifier for the project that task associated with.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::Task;
+    ///
+    /// let task = Task::create("Test Task");
+    /// assert_eq!(task.project_id(), &None);
+    /// ```
+    pub fn project_id(&self) -> &Option<ProjectId> {
+        &self.project_id
+    }
+
+    /// Gets the identifier for the section of the project that task is associated with.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::Task;
+    ///
+    /// let task = Task::create("Test Task");
+    /// assert_eq!(task.section_id(), &None);
+    /// ```
+    pub fn section_id(&self) -> &Option<SectionId> {
+        &self.section_id
+    }
+
+    /// Gets the identifier of the parent task, if this is a sub-task.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::Task;
+    ///
+    /// let task = Task::create("Test Task");
+    /// assert_eq!(task.parent_id(), &None);
+    /// ```
+    pub fn parent_id(&self) -> &Option<TaskId> {
+        &self.parent_id
+    }
+
+    /// Gets the task's content.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::Task;
+    ///
+    /// let task = Task::create("Test Task");
+    /// assert_eq!(task.content(), "Test Task");
+    /// ```
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    /// Gets whether the task is completed or not.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::Task;
+    ///
+    /// let task = Task::create("Test Task");
+    /// assert_eq!(task.completed(), false);
+    /// ```
+    pub fn completed(&self) -> bool {
+        self.completed
+    }
+
+    /// Gets the identifiers of the labels associated with the task.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::Task;
+    ///
+    /// let mut task = Task::create("Test Task");
+    /// task.add_label_id(10);
+    /// task.add_label_id(4);
+    /// task.add_label_id(1);
+    /// assert_eq!(task.label_ids(), [10, 4, 1]);
+    /// ```
+    pub fn label_ids(&self) -> Vec<LabelId>{
+        self.label_ids.clone()
+    }
+
+    /// Gets whether a label is associated with the task.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::Task;
+    ///
+    /// let mut task = Task::create("Test Task");
+    /// task.add_label_id(10);
+    /// assert!(task.has_label(10));
+    /// assert!(!task.has_label(4));
+    /// ```
+    pub fn has_label(&self, label_id: LabelId) -> bool {
+        self.label_ids.contains(&label_id)
+    }
+
+    /// Gets the names of the labels associated with the task.
+    ///
+    /// The names are only returned by `TodoistClient::quick_add`, which parses them from the
+    /// text; other endpoints identify labels by `label_ids`. They are never sent to the server.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate serde_json;
+    /// # extern crate todoist_rest;
+    /// use todoist_rest::model::task::Task;
+    ///
+    /// # fn main() {
+    /// let task: Task = serde_json::from_str(r#"{"content": "Buy milk", "labels": ["errand"]}"#)
+    ///     .unwrap();
+    /// assert_eq!(task.labels(), ["errand"]);
+    /// assert!(Task::create("Buy milk").labels().is_empty());
+    /// # }
+    /// ```
+    pub fn labels(&self) -> &[String] {
+        &self.labels
+    }
+
+    /// Gets the order of the task with a list of tasks.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::Task;
+    ///
+    /// let task = Task::create("Test Task");
+    /// assert_eq!(task.order(), &None);
+    /// ```
+    pub fn order(&self) -> &Option<u32>{
+        &self.order
+    }
+
+    /// Gets the indentation level for the task in a list of tasks.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::Task;
+    ///
+    /// let task = Task::create("Test Task");
+    /// assert_eq!(task.indent(), &None);
+    /// ```
+    pub fn indent(&self) -> &Option<u32>{
+        &self.indent
+    }
+
+    /// Gets the priority of the task.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::{Task, Priority};
+    ///
+    /// let mut task = Task::create("Test Task");
+    /// task.set_priority(Priority::High);
+    /// assert_eq!(task.priority(), Priority::High);
+    /// ```
+    pub fn priority(&self) -> Priority {
+        self.priority
+    }
+
+    /// Gets the priority of the task as displayed in the Todoist apps, where "p1" is urgent and
+    /// "p4" is normal.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::{Task, Priority};
+    ///
+    /// let mut task = Task::create("Test Task");
+    /// assert_eq!(task.ui_priority(), 4);
+    /// task.set_priority(Priority::Urgent);
+    /// assert_eq!(task.ui_priority(), 1);
+    /// ```
+    pub fn ui_priority(&self) -> u8 {
+        self.priority.ui_priority()
+    }
+
+    /// Gets information about when the task is due.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::{Task, Due};
+    ///
+    /// let mut due = Due::create("tomorrow at noon");
+    /// due.set_date_validated("2017-12-25").unwrap();
+    /// let mut task = Task::create("Test Task");
+    /// task.set_due(Some(due));
+    /// assert_eq!(task.due().unwrap().date(), Some(String::from("2017-12-25")));
+    /// ```
+    pub fn due(&self) -> Option<Due> {
+        self.due.clone()
+    }
+
+    /// Gets the URL on the Todoist site where the full task can be viewed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::Task;
+    ///
+    /// let task = Task::create("Test Task");
+    /// assert_eq!(task.url(), &None);
+    /// ```
+    pub fn url(&self) -> &Option<String> {
+        &self.url
+    }
+
+    /// Gets the number of comments associated with the task.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::Task;
+    ///
+    /// let task = Task::create("Test Task");
+    /// assert_eq!(task.comment_count(), &None);
+    /// ```
+    pub fn comment_count(&self) -> &Option<u32> {
+        &self.comment_count
+    }
+
+    /// Gets the identifier of the user the task is assigned to in a shared project.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::Task;
+    ///
+    /// let task = Task::create("Test Task");
+    /// assert_eq!(task.assignee(), &None);
+    /// ```
+    pub fn assignee(&self) -> &Option<UserId> {
+        &self.assignee
+    }
+
+    /// Gets the identifier of the user who assigned the task.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::Task;
+    ///
+    /// let task = Task::create("Test Task");
+    /// assert_eq!(task.assigner(), &None);
+    /// ```
+    pub fn assigner(&self) -> &Option<UserId> {
+        &self.assigner
+    }
+
+    /// Gets the date and time when the task was created in RFC3339 format in UTC.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::Task;
+    ///
+    /// let task = Task::create("Test Task");
+    /// assert_eq!(task.created(), &None);
+    /// ```
+    pub fn created(&self) -> &Option<String> {
+        &self.created
+    }
+
+    /// Gets whether the task is uncompleted and past due at the given point in time, as decided
+    /// by `Due::is_overdue_at`.
+    ///
+    /// Returns `false` if the task has no due date.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate chrono;
+    /// # extern crate todoist_rest;
+    /// use chrono::{TimeZone, Utc};
+    /// use todoist_rest::model::task::{Task, Due};
+    ///
+    /// # fn main() {
+    /// let mut due = Due::create("christmas");
+    /// due.set_date_validated("2017-12-25").unwrap();
+    /// let task = Task::create_with_due("Test Task", due);
+    ///
+    /// assert!(!task.is_overdue(Utc.with_ymd_and_hms(2017, 12, 25, 12, 0, 0).unwrap()));
+    /// assert!(task.is_overdue(Utc.with_ymd_and_hms(2017, 12, 26, 0, 0, 0).unwrap()));
+    /// # }
+    /// ```
+    #[cfg(feature = "chrono")]
+    pub fn is_overdue(&self, now: DateTime<Utc>) -> bool {
+        !self.completed &&
+            self.due.as_ref().is_some_and(|due| due.is_overdue_at(SystemTime::from(now)))
+    }
+
+    /// Checks whether the task satisfies every criterion set on the filter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::{Task, Filter, Priority};
+    ///
+    /// let mut task = Task::create("Buy milk");
+    /// task.set_priority(Priority::High);
+    ///
+    /// let mut filter = Filter::new();
+    /// filter.set_content_contains(Some("milk"));
+    /// assert!(task.matches_filter(&filter));
+    /// filter.set_priority(Some(Priority::Urgent));
+    /// assert!(!task.matches_filter(&filter));
+    /// ```
+    pub fn matches_filter(&self, filter: &Filter) -> bool {
+        let due_day = self.due.as_ref().and_then(Due::sort_key).map(|(day, _)| day);
+        let due_matches = |date: &Option<String>, ordering| match *date {
+            Some(ref date) => due_day.is_some_and(|day| day.cmp(date.as_str()) == ordering),
+            None => true
+        };
+
+        filter.project_id.is_none_or(|id| self.project_id == Some(id)) &&
+            filter.label_id.is_none_or(|id| self.has_label(id)) &&
+            filter.priority.is_none_or(|priority| self.priority == priority) &&
+            filter.completed.is_none_or(|completed| self.completed == completed) &&
+            due_matches(&filter.due_before, Ordering::Less) &&
+            due_matches(&filter.due_after, Ordering::Greater) &&
+            filter.content_contains.as_ref().is_none_or(|text| {
+                self.content.to_lowercase().contains(&text.to_lowercase())
+            })
+    }
+
+    /// Compares tasks by when they are due, the way the Todoist apps sort them.
+    ///
+    /// Tasks due on an earlier day come first, and on the same day whole-day tasks come before
+    /// tasks due at an exact time. Tasks without a due date come last. Tasks that are due at the
+    /// same time are compared by their order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::{Task, Due};
+    ///
+    /// let mut due = Due::create("christmas");
+    /// due.set_date_validated("2017-12-25").unwrap();
+    /// let mut first = Task::create("First");
+    /// first.set_due(Some(due));
+    /// let second = Task::create("Second");
+    ///
+    /// let mut tasks = vec![second, first];
+    /// tasks.sort_by(Task::cmp_by_due);
+    /// assert_eq!(tasks[0].content(), "First");
+    /// ```
+    pub fn cmp_by_due(&self, other: &Task) -> Ordering {
+        self.cmp_due(other).then_with(|| self.cmp_order(other))
+    }
+
+    /// Compares tasks by priority, with urgent tasks first.
+    ///
+    /// Tasks with the same priority are compared by their order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::{Task, Priority};
+    ///
+    /// let mut urgent = Task::create("Urgent");
+    /// urgent.set_priority(Priority::Urgent);
+    /// let normal = Task::create("Normal");
+    ///
+    /// let mut tasks = vec![normal, urgent];
+    /// tasks.sort_by(Task::cmp_by_priority);
+    /// assert_eq!(tasks[0].content(), "Urgent");
+    /// ```
+    pub fn cmp_by_priority(&self, other: &Task) -> Ordering {
+        self.cmp_priority(other).then_with(|| self.cmp_order(other))
+    }
+
+    /// Compares tasks the way the Todoist apps sort them by default: by when they are due, then
+    /// by priority with urgent tasks first, then by their order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::{Task, Priority};
+    ///
+    /// let mut urgent = Task::create("Urgent");
+    /// urgent.set_priority(Priority::Urgent);
+    /// let mut normal = Task::create("Normal");
+    /// normal.set_order(Some(1));
+    ///
+    /// let mut tasks = vec![normal, urgent];
+    /// tasks.sort_by(Task::cmp_default);
+    /// assert_eq!(tasks[0].content(), "Urgent");
+    /// ```
+    pub fn cmp_default(&self, other: &Task) -> Ordering {
+        self.cmp_due(other)
+            .then_with(|| self.cmp_priority(other))
+            .then_with(|| self.cmp_order(other))
+    }
+
+    /// Compares the due dates of the tasks, with tasks without a due date last.
+    fn cmp_due(&self, other: &Task) -> Ordering {
+        let key = self.due.as_ref().and_then(Due::sort_key);
+        let other_key = other.due.as_ref().and_then(Due::sort_key);
+
+        match (key, other_key) {
+            (Some(key), Some(other_key)) => key.cmp(&other_key),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal
+        }
+    }
+
+    /// Compares the priorities of the tasks, with urgent tasks first.
+    fn cmp_priority(&self, other: &Task) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+
+    /// Compares the order of the tasks, with tasks without an order last.
+    fn cmp_order(&self, other: &Task) -> Ordering {
+        match (self.order, other.order) {
+            (Some(order), Some(other_order)) => order.cmp(&other_order),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal
+        }
+    }
+
+    /// Compares the remaining fields of the tasks, so that only equal tasks compare as equal.
+    fn cmp_fields(&self, other: &Task) -> Ordering {
+        (self.id, self.project_id, self.section_id, self.parent_id, &self.content, self.completed,
+         &self.label_ids, self.indent, &self.due, &self.url, self.comment_count)
+            .cmp(&(other.id, other.project_id, other.section_id, other.parent_id, &other.content,
+                   other.completed, &other.label_ids, other.indent, &other.due, &other.url,
+                   other.comment_count))
+            .then_with(|| {
+                (&self.labels, self.assignee, self.assigner, &self.created)
+                    .cmp(&(&other.labels, other.assignee, other.assigner, &other.created))
+            })
+    }
+}
+
+impl Serialize for Task {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where
+        S: Serializer {
+        let mut len = 2;
+
+        for is_set in &[self.project_id.is_some(), self.parent_id.is_some(), self.indent.is_some(),
+                        self.sends_label_ids(), self.assignee.is_some()] {
+            if *is_set {
+                len += 1;
+            }
+        }
+
+        let due_fields = self.due.as_ref().map(due_fields).unwrap_or_default();
+        len += due_fields.len();
+
+        let mut state = serializer.serialize_struct("Task", len)?;
+
+        state.serialize_field("content", &self.content)?;
+
+        if let Some(project_id) = self.project_id {
+            state.serialize_field("project_id", &Id(project_id))?;
+        }
+
+        if let Some(parent_id) = self.parent_id {
+            state.serialize_field("parent_id", &Id(parent_id))?;
+        }
+
+        if let Some(ref indent) = self.indent {
+            state.serialize_field("indent", indent)?;
+        }
+
+        if self.sends_label_ids() {
+            state.serialize_field("label_ids", &self.label_id_values())?;
+        }
+
+        state.serialize_field("priority", &self.priority)?;
+
+        if let Some(assignee) = self.assignee {
+            state.serialize_field("assignee", &Id(assignee))?;
+        }
+
+        for (name, value) in due_fields {
+            state.serialize_field(name, value)?;
+        }
+
+        state.end()
+    }
+}
+
+/// Builder for a new task, started with `Task::builder`.
+///
+/// The values are validated all at once when the task is built.
+#[derive(Debug)]
+pub struct TaskBuilder {
+    /// The task being built
+    task: Task,
+    /// Indentation level, validated when the task is built
+    indent: Option<u32>
+}
+
+impl TaskBuilder {
+    /// Sets the identifier of the project to create the task in.
+    pub fn project_id(mut self, project_id: ProjectId) -> TaskBuilder {
+        self.task.set_project_id(Some(project_id));
+        self
+    }
+
+    /// Sets the identifier of the parent task, making the task a sub-task.
+    pub fn parent_id(mut self, parent_id: TaskId) -> TaskBuilder {
+        self.task.set_parent_id(Some(parent_id));
+        self
+    }
+
+    /// Sets the priority of the task.
+    pub fn priority(mut self, priority: Priority) -> TaskBuilder {
+        self.task.set_priority(priority);
+        self
+    }
+
+    /// Sets when the task is due, from a `Due` or its human-defined representation.
+    pub fn due<D: Into<Due>>(mut self, due: D) -> TaskBuilder {
+        self.task.set_due(Some(due.into()));
+        self
+    }
+
+    /// Associates a label to the task.
+    pub fn label_id(mut self, label_id: LabelId) -> TaskBuilder {
+        self.task.add_label_id(label_id);
+        self
+    }
+
+    /// Sets the position of the task within the project, which is not sent to the server.
+    pub fn order(mut self, order: u32) -> TaskBuilder {
+        self.task.set_order(Some(order));
+        self
+    }
+
+    /// Sets the indentation level of the task from 1 to 5.
+    pub fn indent(mut self, indent: u32) -> TaskBuilder {
+        self.indent = Some(indent);
+        self
+    }
+
+    /// Builds the task.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::{Task, TaskBuildError};
+    ///
+    /// assert_eq!(Task::builder(" ").build().unwrap_err(), TaskBuildError::EmptyContent);
+    /// assert!(Task::builder("Buy milk").indent(6).build().is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TaskBuildError` if the content is empty or the indentation level is not in the
+    /// range of 1 to 5.
+    pub fn build(self) -> Result<Task, TaskBuildError> {
+        let mut task = self.task;
+
+        if task.content.trim().is_empty() {
+            return Err(TaskBuildError::EmptyContent);
+        }
+
+        if let Some(indent) = self.indent {
+            task.set_indent(indent)?;
+        }
+
+        Ok(task)
+    }
+}
+
+/// Tasks are equal when all their fields are, regardless of which fields were changed since the
+/// task was created or deserialized.
+impl PartialEq for Task {
+    fn eq(&self, other: &Task) -> bool {
+        self.id == other.id &&
+            self.project_id == other.project_id &&
+            self.section_id == other.section_id &&
+            self.parent_id == other.parent_id &&
+            self.content == other.content &&
+            self.completed == other.completed &&
+            self.label_ids == other.label_ids &&
+            self.labels == other.labels &&
+            self.order == other.order &&
+            self.indent == other.indent &&
+            self.priority == other.priority &&
+            self.due == other.due &&
+            self.url == other.url &&
+            self.comment_count == other.comment_count &&
+            self.assignee == other.assignee &&
+            self.assigner == other.assigner &&
+            self.created == other.created
+    }
+}
+
+impl Eq for Task {}
+
+/// Orders tasks by descending priority, then by ascending due date with tasks without a due date
+/// last, then by their order.
+///
+/// Tasks that tie on all of these are ordered by their remaining fields, so that only equal tasks
+/// compare as equal.
+///
+/// # Example
+///
+/// ```
+/// use todoist_rest::model::task::{Task, Priority};
+///
+/// let mut normal = Task::create("Normal");
+/// normal.set_priority(Priority::Normal);
+/// let mut urgent = Task::create("Urgent");
+/// urgent.set_priority(Priority::Urgent);
+///
+/// let mut tasks = vec![normal, urgent];
+/// tasks.sort();
+/// assert_eq!(tasks[0].content(), "Urgent");
+/// ```
+impl Ord for Task {
+    fn cmp(&self, other: &Task) -> Ordering {
+        self.cmp_priority(other)
+            .then_with(|| self.cmp_due(other))
+            .then_with(|| self.cmp_order(other))
+            .then_with(|| self.cmp_fields(other))
+    }
+}
+
+impl PartialOrd for Task {
+    fn partial_cmp(&self, other: &Task) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Formats the task as a single line for display, such as `[p2] Test Task (due: 2017-12-25)`.
+///
+/// The priority is shown as in the Todoist apps, where "p1" is urgent. Completed tasks are
+/// prefixed with `[x]`, and the due date/time is shown if there is one, falling back to the human
+/// defined due string.
+impl fmt::Display for Task {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.completed {
+            write!(f, "[x] ")?;
+        }
+
+        write!(f, "[p{}] {}", self.priority.ui_priority(), self.content)?;
+
+        if let Some(ref due) = self.due {
+            let summary = due.datetime.as_ref().or(due.date.as_ref()).unwrap_or(&due.string);
+            write!(f, " (due: {})", summary)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The field `sort_tasks_by` sorts tasks by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskSortKey {
+    /// The position of the task in its project
+    Order,
+    /// The priority of the task, from normal to urgent
+    Priority,
+    /// The day the task is due, with whole-day tasks before tasks due at an exact time
+    DueDate,
+    /// The content of the task
+    Content
+}
+
+/// The direction `sort_tasks_by` sorts tasks in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortDirection {
+    /// From the lowest to the highest value, with tasks without a value last
+    Ascending,
+    /// From the highest to the lowest value, with tasks without a value first
+    Descending
+}
+
+/// Sorts tasks in place by the given field and direction.
+///
+/// Tasks without an order or due date come last when sorting in ascending direction and first
+/// when sorting in descending direction. Tasks with equal values keep their relative order.
+///
+/// # Example
+///
+/// ```
+/// use todoist_rest::model::task::{Task, Priority, TaskSortKey, SortDirection, sort_tasks_by};
+///
+/// let mut urgent = Task::create("Urgent");
+/// urgent.set_priority(Priority::Urgent);
+/// let mut tasks = vec![Task::create("Normal"), urgent];
+///
+/// sort_tasks_by(&mut tasks, TaskSortKey::Priority, SortDirection::Descending);
+/// assert_eq!(tasks[0].content(), "Urgent");
+/// ```
+pub fn sort_tasks_by(tasks: &mut [Task], key: TaskSortKey, direction: SortDirection) {
+    let compare = |task: &Task, other: &Task| match key {
+        TaskSortKey::Order => task.cmp_order(other),
+        TaskSortKey::Priority => task.priority.cmp(&other.priority),
+        TaskSortKey::DueDate => task.cmp_due(other),
+        TaskSortKey::Content => task.content.cmp(&other.content)
+    };
+
+    match direction {
+        SortDirection::Ascending => tasks.sort_by(compare),
+        SortDirection::Descending => tasks.sort_by(|task, other| compare(other, task))
+    }
+}
+
+/// Criteria for selecting tasks from a list that is already loaded, without querying the API.
+///
+/// A task matches the filter when it satisfies every criterion that is set.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Filter {
+    /// Only match tasks of the project with this identifier
+    project_id: Option<ProjectId>,
+    /// Only match tasks with the label with this identifier
+    label_id: Option<LabelId>,
+    /// Only match tasks with this priority
+    priority: Option<Priority>,
+    /// Only match completed or uncompleted tasks
+    completed: Option<bool>,
+    /// Only match tasks due on a day before this date in YYYY-MM-DD format
+    due_before: Option<String>,
+    /// Only match tasks due on a day after this date in YYYY-MM-DD format
+    due_after: Option<String>,
+    /// Only match tasks whose content contains this text, ignoring case
+    content_contains: Option<String>
+}
+
+impl Filter {
+    /// Creates a new filter matching all tasks.
+    pub fn new() -> Filter {
+        Filter::default()
+    }
+
+    /// Only match tasks of the project with this identifier.
+    pub fn set_project_id(&mut self, project_id: Option<ProjectId>) {
+        self.project_id = project_id;
+    }
+
+    /// Only match tasks with the label with this identifier.
+    pub fn set_label_id(&mut self, label_id: Option<LabelId>) {
+        self.label_id = label_id;
+    }
+
+    /// Only match tasks with this priority.
+    pub fn set_priority(&mut self, priority: Option<Priority>) {
+        self.priority = priority;
+    }
+
+    /// Only match completed or uncompleted tasks.
+    pub fn set_completed(&mut self, completed: Option<bool>) {
+        self.completed = completed;
+    }
+
+    /// Only match tasks due on a day before this date in YYYY-MM-DD format.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DueDateError` carrying the value, and leaves the filter unchanged, if the value
+    /// is not a calendar date in YYYY-MM-DD format.
+    pub fn set_due_before(&mut self, date: Option<&str>) -> Result<(), DueDateError> {
+        self.due_before = validate_filter_date(date)?;
+        Ok(())
+    }
+
+    /// Only match tasks due on a day after this date in YYYY-MM-DD format.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DueDateError` carrying the value, and leaves the filter unchanged, if the value
+    /// is not a calendar date in YYYY-MM-DD format.
+    pub fn set_due_after(&mut self, date: Option<&str>) -> Result<(), DueDateError> {
+        self.due_after = validate_filter_date(date)?;
+        Ok(())
+    }
+
+    /// Only match tasks whose content contains this text, ignoring case.
+    pub fn set_content_contains(&mut self, text: Option<&str>) {
+        self.content_contains = text.map(String::from);
+    }
+
+    /// Gets the identifier of the project to match tasks of.
+    pub fn project_id(&self) -> &Option<ProjectId> {
+        &self.project_id
+    }
+
+    /// Gets the identifier of the label to match tasks with.
+    pub fn label_id(&self) -> &Option<LabelId> {
+        &self.label_id
+    }
+
+    /// Gets the priority to match tasks with.
+    pub fn priority(&self) -> &Option<Priority> {
+        &self.priority
+    }
+
+    /// Gets whether to match completed or uncompleted tasks.
+    pub fn completed(&self) -> &Option<bool> {
+        &self.completed
+    }
+
+    /// Gets the date that matched tasks are due before.
+    pub fn due_before(&self) -> &Option<String> {
+        &self.due_before
+    }
+
+    /// Gets the date that matched tasks are due after.
+    pub fn due_after(&self) -> &Option<String> {
+        &self.due_after
+    }
+
+    /// Gets the text that the content of matched tasks contains.
+    pub fn content_contains(&self) -> &Option<String> {
+        &self.content_contains
+    }
+}
+
+/// Checks that a filter date is a calendar date in YYYY-MM-DD format.
+fn validate_filter_date(date: Option<&str>) -> Result<Option<String>, DueDateError> {
+    match date {
+        Some(date) if !is_valid_date(date) => Err(DueDateError {
+            value: String::from(date),
+            expected: "a calendar date in YYYY-MM-DD format"
+        }),
+        _ => Ok(date.map(String::from))
+    }
+}
+
+impl FromStr for Task {
+    type Err = serde_json::Error;
+
+    /// Parses a task from its JSON representation returned by the API.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use todoist_rest::model::task::Task;
+    ///
+    /// let task: Task = r#"{"id": 1234, "content": "Buy milk"}"#.parse().unwrap();
+    /// assert_eq!(task.id(), &Some(1234));
+    /// assert_eq!(task.content(), "Buy milk");
+    /// ```
+    fn from_str(json: &str) -> Result<Task, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate serde_json;
+    extern crate serde_test;
+    use std::cmp::Ordering;
+    use std::convert::TryFrom;
+    use model::task::Task;
+    use model::task::Due;
+    use model::task::Priority;
+    use model::task::TaskBuildError;
+    use model::task::Filter;
+    use model::task::{TaskSortKey, SortDirection, sort_tasks_by};
+    use serde_json::{Map, Value};
+
+    #[test]
+    fn create_due() {
+        let due = Due::create("tomorrow at noon");
+        assert_eq!(due.string(), "tomorrow at noon");
+    }
+
+    #[test]
+    fn set_due_date() {
+        let mut due = Due::create("tomorrow at noon");
+        due.set_date_validated("2017-12-25").unwrap();
+        assert_eq!(due.date(), Some(String::from("2017-12-25")));
+        assert_eq!(due.string(), "2017-12-25");
+    }
+
+    #[test]
+    fn set_task_due_date() {
+        let mut due = Due::create("tomorrow at noon");
+        due.set_date_validated("2017-12-25").unwrap();
+        let mut task = Task::create("Test Task");
+        task.set_due(Some(due));
+        assert_eq!(task.due().unwrap().date(), Some(String::from("2017-12-25")));
+
+        let json = serde_json::to_string(&task).unwrap();
+        println!("{}", json);
+        assert!(json.contains("\"due_date\":\"2017-12-25\""));
+    }
+
+    #[test]
+    fn create_and_serialize_task() {
+        let new_task = Task::create("Test Task");
+        let json = serde_json::to_string(&new_task).unwrap();
+        println!("{}", json);
+        assert_eq!(json, r#"{"content":"Test Task","priority":1}"#);
+    }
+
+    #[test]
+    fn deserialize_task() {
+        let json = r#"
+            {
+                "comment_count": 10,
+                "completed": true,
+                "content": "My task",
+                "due": {
+                    "date": "2016-09-01",
+                    "recurring": true,
+                    "datetime": "2016-09-01T09:00:00Z",
+                    "string": "tomorrow at 12",
+                    "timezone": "Europe/Moscow"
+                },
+                "id": 1234,
+                "indent": 1,
+                "label_ids": [
+                    124,
+                    125,
+                    128
+                ],
+                "order": 123,
+                "priority": 1,
+                "project_id": 2345,
+                "url": "https://todoist.com/showTask?id=12345&sync_id=56789"
+            }
+        "#;
+
+        let task: Task = serde_json::from_str(json).unwrap();
+        println!("{:?}", task);
+        println!("{}", serde_json::to_string(&task).unwrap());
+    }
+
+    #[test]
+    fn update_task_properties() {
+        let mut task = Task::create("Test Task");
+        task.set_content("New Task Name");
+        task.set_priority(Priority::High);
+        task.set_completed(true);
+        task.add_label_id(10);
+        task.add_label_id(4);
+        task.add_label_id(1);
+        assert_eq!(task.label_ids(), [10, 4, 1]);
+        task.remove_label_id(4);
+        assert_eq!(task.label_ids(), [10, 1]);
+
+        println!("{:?}", task);
+
+        let json = serde_json::to_string(&task).unwrap();
+        println!("{}", json);
+        assert!(json.contains("New Task Name"));
+        assert!(json.contains("\"priority\":3"));
+    }
+
+    #[test]
+    fn convert_priority() {
+        assert_eq!(Priority::try_from(1).unwrap(), Priority::Normal);
+        assert_eq!(Priority::try_from(2).unwrap(), Priority::Medium);
+        assert_eq!(Priority::try_from(3).unwrap(), Priority::High);
+        assert_eq!(Priority::try_from(4).unwrap(), Priority::Urgent);
+        assert_eq!(Priority::try_from(0).unwrap_err().value(), 0);
+        assert_eq!(Priority::try_from(5).unwrap_err().value(), 5);
+        assert_eq!(Priority::saturating_from(0), Priority::Normal);
+        assert_eq!(Priority::saturating_from(9), Priority::Urgent);
+        assert_eq!(u32::from(Priority::Medium), 2);
+    }
+
+    #[test]
+    fn try_set_task_priority() {
+        let mut task = Task::create("Test Task");
+        assert_eq!(task.try_set_priority(0).unwrap_err().value(), 0);
+        assert_eq!(task.priority(), Priority::Normal);
+        assert!(task.try_set_priority(4).is_ok());
+        assert_eq!(task.priority(), Priority::Urgent);
+        assert_eq!(task.try_set_priority(5).unwrap_err().value(), 5);
+        assert_eq!(task.priority(), Priority::Urgent);
+        assert!(task.try_set_priority(1).is_ok());
+        assert_eq!(task.priority(), Priority::Normal);
+    }
+
+    #[test]
+    fn create_and_serialize_sub_task() {
+        let mut sub_task = Task::create("Sub Task");
+        let json = serde_json::to_string(&sub_task).unwrap();
+        assert!(!json.contains("parent_id"));
+
+        sub_task.set_parent_id(Some(1234));
+        let json = serde_json::to_string(&sub_task).unwrap();
+        println!("{}", json);
+        assert!(json.contains("\"parent_id\":1234"));
+    }
+
+    #[test]
+    fn deserialize_sub_task() {
+        let json = r#"
+            {
+                "completed": false,
+                "content": "My sub-task",
+                "id": 1235,
+                "label_ids": [],
+                "parent_id": 1234,
+                "priority": 1,
+                "project_id": 2345
+            }
+        "#;
+
+        let task: Task = serde_json::from_str(json).unwrap();
+        assert_eq!(task.parent_id(), &Some(1234));
+    }
+
+    #[test]
+    fn convert_ui_priority() {
+        assert_eq!(Priority::from_ui_priority(1).unwrap(), Priority::Urgent);
+        assert_eq!(Priority::from_ui_priority(2).unwrap(), Priority::High);
+        assert_eq!(Priority::from_ui_priority(3).unwrap(), Priority::Medium);
+        assert_eq!(Priority::from_ui_priority(4).unwrap(), Priority::Normal);
+        assert_eq!(Priority::from_ui_priority(5).unwrap_err().value(), 5);
+
+        let mut task = Task::create("Test Task");
+        task.try_set_priority(4).unwrap();
+        assert_eq!(task.ui_priority(), 1);
+        task.try_set_priority(1).unwrap();
+        assert_eq!(task.ui_priority(), 4);
+
+        task.set_ui_priority(1).unwrap();
+        let json = serde_json::to_string(&task).unwrap();
+        println!("{}", json);
+        assert!(json.contains("\"priority\":4"));
+    }
+
+    #[test]
+    fn serialize_ui_priority() {
+        for ui_priority in 1..=4u8 {
+            let mut task = Task::create("Test Task");
+            task.set_ui_priority(ui_priority).unwrap();
+            let json = serde_json::to_string(&task).unwrap();
+            assert!(json.contains(&format!("\"priority\":{}", 5 - ui_priority)));
+        }
+
+        let mut task = Task::create("Test Task");
+        task.set_ui_priority(2).unwrap();
+        assert_eq!(task.set_ui_priority(0).unwrap_err().value(), 0);
+        assert_eq!(task.set_ui_priority(5).unwrap_err().value(), 5);
+        assert_eq!(task.ui_priority(), 2);
+        assert_eq!(task.priority(), Priority::High);
+    }
+
+    #[test]
+    fn add_and_remove_label_ids() {
+        let mut task = Task::create("Test Task");
+        assert!(task.add_label_id(10));
+        assert!(!task.add_label_id(10));
+        assert_eq!(task.label_ids(), [10]);
+        assert!(!task.remove_label_id(4));
+        assert_eq!(task.label_ids(), [10]);
+
+        let json = r#"
+            {
+                "completed": false,
+                "content": "My task",
+                "label_ids": [124, 125, 124, 128],
+                "priority": 1
+            }
+        "#;
+
+        let mut task: Task = serde_json::from_str(json).unwrap();
+        assert!(task.remove_label_id(124));
+        assert_eq!(task.label_ids(), [125, 128]);
+        assert!(!task.remove_label_id(124));
+    }
+
+    #[test]
+    fn replace_label_ids() {
+        let mut task = Task::create("Test Task");
+        task.set_label_ids(vec![10, 4, 1]);
+        assert_eq!(task.label_ids(), [10, 4, 1]);
+        task.set_label_ids(vec![7, 7, 2]);
+        assert_eq!(task.label_ids(), [7, 2]);
+        assert!(task.has_label(7));
+        assert!(task.has_label(2));
+        assert!(!task.has_label(10));
+
+        let json = serde_json::to_string(&task).unwrap();
+        println!("{}", json);
+        assert!(json.contains("\"label_ids\":[7,2]"));
+
+        task.clear_label_ids();
+        assert!(task.label_ids().is_empty());
+        assert!(!task.has_label(7));
+    }
+
+    #[test]
+    fn serialize_task_order_and_indent() {
+        let mut task = Task::create("Test Task");
+        task.set_order(Some(5));
+        task.set_indent(2).unwrap();
+        assert_eq!(task.set_indent(0).unwrap_err().value(), 0);
+        assert_eq!(task.set_indent(6).unwrap_err().value(), 6);
+
+        let json = serde_json::to_string(&task).unwrap();
+        println!("{}", json);
+        assert_eq!(json, r#"{"content":"Test Task","indent":2,"priority":1}"#);
+    }
+
+    fn sort_fixture() -> Vec<Task> {
+        let mut tasks = vec![];
+
+        let mut task = Task::create("no due, urgent");
+        task.set_priority(Priority::Urgent);
+        task.set_order(Some(1));
+        tasks.push(task);
+
+        let mut task = Task::create("no due, normal");
+        task.set_order(Some(2));
+        tasks.push(task);
+
+        let mut due = Due::create("christmas at noon");
+        due.set_datetime("2017-12-25T12:00:00Z");
+        let mut task = Task::create("christmas at noon, normal");
+        task.set_due(Some(due));
+        task.set_order(Some(3));
+        tasks.push(task);
+
+        let mut due = Due::create("christmas");
+        due.set_date_validated("2017-12-25").unwrap();
+        let mut task = Task::create("christmas, normal");
+        task.set_due(Some(due));
+        task.set_order(Some(4));
+        tasks.push(task);
+
+        let mut due = Due::create("christmas");
+        due.set_date_validated("2017-12-25").unwrap();
+        let mut task = Task::create("christmas, high");
+        task.set_due(Some(due));
+        task.set_priority(Priority::High);
+        task.set_order(Some(5));
+        tasks.push(task);
+
+        let mut due = Due::create("christmas eve");
+        due.set_date_validated("2017-12-24").unwrap();
+        let mut task = Task::create("christmas eve, normal");
+        task.set_due(Some(due));
+        task.set_order(Some(6));
+        tasks.push(task);
+
+        tasks.swap(0, 5);
+        tasks.swap(1, 3);
+        tasks
+    }
+
+    fn contents(tasks: &[Task]) -> Vec<&str> {
+        tasks.iter().map(Task::content).collect()
+    }
+
+    #[test]
+    fn sort_tasks_by_due() {
+        let mut tasks = sort_fixture();
+        tasks.sort_by(Task::cmp_by_due);
+        assert_eq!(contents(&tasks), ["christmas eve, normal", "christmas, normal", "christmas, high",
+                                      "christmas at noon, normal", "no due, urgent", "no due, normal"]);
+    }
+
+    #[test]
+    fn sort_tasks_by_priority() {
+        let mut tasks = sort_fixture();
+        tasks.sort_by(Task::cmp_by_priority);
+        assert_eq!(contents(&tasks), ["no due, urgent", "christmas, high", "no due, normal",
+                                      "christmas at noon, normal", "christmas, normal",
+                                      "christmas eve, normal"]);
+    }
+
+    #[test]
+    fn sort_tasks_by_default() {
+        let mut tasks = sort_fixture();
+        tasks.sort_by(Task::cmp_default);
+        assert_eq!(contents(&tasks), ["christmas eve, normal", "christmas, high", "christmas, normal",
+                                      "christmas at noon, normal", "no due, urgent", "no due, normal"]);
+    }
+
+    #[test]
+    fn deserialize_task_with_new_field_names() {
+        let legacy = r#"{"content": "My task", "completed": true, "label_ids": [], "priority": 1}"#;
+        let current = r#"{"content": "My task", "is_completed": true, "label_ids": [], "priority": 1}"#;
+
+        let legacy: Task = serde_json::from_str(legacy).unwrap();
+        let current: Task = serde_json::from_str(current).unwrap();
+        assert!(legacy.completed());
+        assert_eq!(legacy.completed(), current.completed());
+    }
+
+    #[test]
+    fn deserialize_sparse_task() {
+        let task: Task = serde_json::from_str(r#"{"content": "My task"}"#).unwrap();
+        assert_eq!(task.content(), "My task");
+        assert!(!task.completed());
+        assert!(task.label_ids().is_empty());
+        assert_eq!(task.priority(), Priority::Normal);
+        assert!(task.due().is_none());
+
+        let task: Task = serde_json::from_str(r#"{"content": "My task", "due": {"string": "today"}}"#)
+            .unwrap();
+        assert_eq!(task.due().unwrap().string(), "today");
+    }
+
+    #[test]
+    fn deserialize_task_with_invalid_priority() {
+        let json = r#"
+            {
+                "completed": false,
+                "content": "My task",
+                "label_ids": [],
+                "priority": 5
+            }
+        "#;
+
+        let result: Result<Task, _> = serde_json::from_str(json);
+        let error = result.unwrap_err();
+        println!("{}", error);
+        assert!(error.to_string().contains("invalid priority 5"));
+    }
+
+    #[test]
+    fn update_payload_with_only_priority() {
+        let json = r#"
+            {
+                "id": 123,
+                "project_id": 456,
+                "content": "My task",
+                "label_ids": [1, 2],
+                "order": 3,
+                "indent": 1,
+                "priority": 1
+            }
+        "#;
+
+        let mut task: Task = serde_json::from_str(json).unwrap();
+        assert_eq!(task.update_payload().to_string(), "{}");
+        task.set_priority(Priority::High);
+        assert_eq!(task.update_payload().to_string(), r#"{"priority":3}"#);
+    }
+
+    #[test]
+    fn update_payload_with_changed_fields() {
+        let mut task = Task::create("Test Task");
+        task.set_content("New Task Name");
+        task.remove_label_id(8);
+        task.add_label_id(4);
+        task.set_due(None);
+        assert_eq!(task.update_payload().to_string(),
+                   r#"{"content":"New Task Name","due_string":"no date","label_ids":[4]}"#);
+
+        task.reset_changes();
+        assert_eq!(task.update_payload().to_string(), "{}");
+        assert!(!task.remove_label_id(8));
+        assert_eq!(task.update_payload().to_string(), "{}");
+    }
+
+    #[test]
+    fn create_and_update_json() {
+        let json = r#"
+            {
+                "id": 123,
+                "project_id": 456,
+                "parent_id": 789,
+                "content": "My task",
+                "label_ids": [1, 2],
+                "order": 3,
+                "indent": 2,
+                "priority": 4,
+                "due": {"string": "Dec 25", "date": "2017-12-25"}
+            }
+        "#;
+
+        let task: Task = serde_json::from_str(json).unwrap();
+        let create = task.to_create_json().unwrap();
+        assert!(create.contains(r#""project_id":456"#));
+        assert!(create.contains(r#""parent_id":789"#));
+
+        let update = task.to_update_json().unwrap();
+        assert!(!update.contains("project_id"));
+        assert_eq!(update,
+                   r#"{"content":"My task","label_ids":[1,2],"priority":4,"due_date":"2017-12-25"}"#);
+    }
+
+    #[test]
+    fn update_json_label_ids() {
+        let json = r#"{"id": 123, "content": "My task", "priority": 2}"#;
+        let mut task: Task = serde_json::from_str(json).unwrap();
+        assert_eq!(task.to_update_json().unwrap(), r#"{"content":"My task","priority":2}"#);
+
+        task.add_label_id(4);
+        assert_eq!(task.to_update_json().unwrap(),
+                   r#"{"content":"My task","label_ids":[4],"priority":2}"#);
+
+        task.clear_label_ids();
+        assert_eq!(task.to_update_json().unwrap(),
+                   r#"{"content":"My task","label_ids":[],"priority":2}"#);
+    }
+
+    #[test]
+    fn serialize_task_label_ids() {
+        let mut task = Task::create("Test Task");
+        let json = serde_json::to_string(&task).unwrap();
+        assert_eq!(json, r#"{"content":"Test Task","priority":1}"#);
+
+        task.set_label_ids(vec![10, 4]);
+        let json = serde_json::to_string(&task).unwrap();
+        assert_eq!(json, r#"{"content":"Test Task","label_ids":[10,4],"priority":1}"#);
+
+        task.reset_changes();
+        task.remove_label_id(10);
+        task.remove_label_id(4);
+        let json = serde_json::to_string(&task).unwrap();
+        assert_eq!(json, r#"{"content":"Test Task","label_ids":[],"priority":1}"#);
+
+        task.reset_changes();
+        let json = serde_json::to_string(&task).unwrap();
+        assert_eq!(json, r#"{"content":"Test Task","priority":1}"#);
+    }
+
+    #[test]
+    fn set_validated_due_date() {
+        let mut due = Due::create("tomorrow at noon");
+        assert!(due.set_date_validated("2020-02-29").is_ok());
+        assert_eq!(due.date(), Some(String::from("2020-02-29")));
+
+        for date in &["25-12-2017", "2017-12-5", "2017/12/25", "2017-13-01", "2017-00-10",
+                      "2017-12-32", "2021-02-29", "1900-02-29", "+017-12-25", "2017-12-25T12:00"] {
+            let error = due.set_date_validated(date).unwrap_err();
+            assert_eq!(error.value(), *date);
+            assert!(error.to_string().ends_with("expected a calendar date in YYYY-MM-DD format"));
+        }
+
+        assert_eq!(due.date(), Some(String::from("2020-02-29")));
+    }
+
+    #[test]
+    fn serialize_task_due_lang() {
+        let mut due = Due::create("morgen um 9");
+        due.set_lang("de").unwrap();
+        let mut task = Task::create("Test Task");
+        task.set_due(Some(due));
+
+        let json = serde_json::to_string(&task).unwrap();
+        println!("{}", json);
+        assert!(json.contains(r#""due_string":"morgen um 9","due_lang":"de""#));
+        assert_eq!(task.update_payload()["due_lang"], "de");
+        assert!(task.to_update_json().unwrap().contains(r#""due_lang":"de""#));
+
+        task.set_due(Some(Due::create("tomorrow at noon")));
+        let json = serde_json::to_string(&task).unwrap();
+        assert!(json.contains(r#""due_lang":"en""#));
+    }
+
+    #[test]
+    fn serialize_large_ids_as_strings() {
+        let json = r#"
+            {
+                "id": 9007199254740993,
+                "project_id": 9007199254740993,
+                "parent_id": "9007199254740993",
+                "content": "My task",
+                "label_ids": [1, 9007199254740993]
+            }
+        "#;
+
+        let mut task: Task = serde_json::from_str(json).unwrap();
+        assert_eq!(task.id(), &Some(9_007_199_254_740_993));
+        assert_eq!(task.parent_id(), &Some(9_007_199_254_740_993));
+
+        let create = task.to_create_json().unwrap();
+        assert_eq!(create, r#"{"content":"My task","project_id":"9007199254740993","parent_id":"9007199254740993","label_ids":[1,"9007199254740993"],"priority":1}"#);
+        let created: Task = serde_json::from_str(&create).unwrap();
+        assert_eq!(created.project_id(), &Some(9_007_199_254_740_993));
+        assert_eq!(created.label_ids(), [1, 9_007_199_254_740_993]);
+
+        task.set_parent_id(Some(9_007_199_254_740_993));
+        assert_eq!(task.update_payload().to_string(), r#"{"parent_id":"9007199254740993"}"#);
+    }
+
+    #[test]
+    fn same_due_fields_in_every_body() {
+        let mut datetime = Due::create("tomorrow at noon");
+        datetime.set_datetime_validated("2017-12-25T12:00:00Z").unwrap();
+        let mut date = Due::create("Christmas");
+        date.set_date_validated("2017-12-25").unwrap();
+        let mut string = Due::create("morgen");
+        string.set_lang("de").unwrap();
+
+        let expected = vec![
+            r#"{"due_datetime":"2017-12-25T12:00:00Z"}"#,
+            r#"{"due_date":"2017-12-25"}"#,
+            r#"{"due_lang":"de","due_string":"morgen"}"#
+        ];
+
+        let due_only = |body: Value| {
+            let fields: Map<String, Value> = body.as_object().unwrap().iter()
+                .filter(|&(name, _)| name.starts_with("due_"))
+                .map(|(name, value)| (name.clone(), value.clone()))
+                .collect();
+            Value::Object(fields).to_string()
+        };
+
+        for (due, expected) in vec![datetime, date, string].into_iter().zip(expected) {
+            let mut task = Task::create("Test Task");
+            task.set_due(Some(due));
+
+            assert_eq!(due_only(serde_json::to_value(&task).unwrap()), expected);
+            assert_eq!(due_only(task.update_payload()), expected);
+            let update = serde_json::from_str(&task.to_update_json().unwrap()).unwrap();
+            assert_eq!(due_only(update), expected);
+        }
     }
 
-    /// Gets the URL on the Todoist site where the full task can be viewed.
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// use todoist_rest::model::task::Task;
-    ///
-    /// let task = Task::create("Test Task");
-    /// assert_eq!(task.url(), &None);
-    /// ```
-    pub fn url(&self) -> &Option<String> {
-        &self.url
+    #[test]
+    fn set_unsupported_due_lang() {
+        let mut due = Due::create("demain");
+        let error = due.set_lang("fr-CA").unwrap_err();
+        assert_eq!(error.value(), "fr-CA");
+        assert!(error.to_string().starts_with("unsupported due date language \"fr-CA\""));
+        assert_eq!(due.lang(), "en");
     }
 
-    /// Gets the number of comments associated with the task.
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// use todoist_rest::model::task::Task;
-    ///
-    /// let task = Task::create("Test Task");
-    /// assert_eq!(task.comment_count(), &None);
-    /// ```
-    pub fn comment_count(&self) -> &Option<u32> {
-        &self.comment_count
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn convert_due_to_chrono() {
+        use chrono::{NaiveDate, TimeZone, Utc};
+
+        let json = r#"{"string": "tomorrow at 12", "date": "2016-09-01",
+                       "datetime": "2016-09-01T12:00:00+03:00"}"#;
+        let due: Due = serde_json::from_str(json).unwrap();
+        assert_eq!(due.as_naive_date(), NaiveDate::from_ymd_opt(2016, 9, 1));
+        assert_eq!(due.as_datetime(), Utc.with_ymd_and_hms(2016, 9, 1, 9, 0, 0).single());
+
+        let json = r#"{"string": "someday", "date": "2016-02-30", "datetime": "2016-09-01 12:00"}"#;
+        let due: Due = serde_json::from_str(json).unwrap();
+        assert_eq!(due.as_naive_date(), None);
+        assert_eq!(due.as_datetime(), None);
+
+        let due = Due::create("someday");
+        assert_eq!(due.as_naive_date(), None);
+        assert_eq!(due.as_datetime(), None);
     }
-}
 
-impl Serialize for Task {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where
-        S: Serializer {
-        let mut len = 5;
-
-        match &self.due {
-            &Some(ref due) => {
-                match &due.datetime {
-                    &Some(_) => len = 6,
-                    &None => {
-                        match &due.date {
-                            &Some(_) => len = 6,
-                            &None => len = 7
-                        }
-                    }
-                }
-            },
-            &None => ()
-        };
+    #[test]
+    fn set_validated_due_datetime() {
+        let mut due = Due::create("tomorrow at noon");
 
-        let mut state = serializer.serialize_struct("Task", len)?;
+        for datetime in &["2017-12-25T12:00:00Z", "2017-12-25T12:00:00.250Z",
+                          "2017-12-25T12:00:00+00:00", "2016-12-31T23:59:60Z"] {
+            assert!(due.set_datetime_validated(datetime).is_ok());
+            assert_eq!(due.datetime().as_deref(), Some(*datetime));
+        }
 
-        state.serialize_field("content", &self.content)?;
-        state.serialize_field("project_id", &self.project_id)?;
-        state.serialize_field("order", &self.order)?;
-        state.serialize_field("label_ids", &self.label_ids)?;
-        state.serialize_field("priority", &self.priority)?;
+        for datetime in &["2025-06-01T12:00:00+05:30", "2025-06-01T12:00:00-08:00"] {
+            let error = due.set_datetime_validated(datetime).unwrap_err();
+            assert_eq!(error.value(), *datetime);
+            assert!(error.to_string().contains("in UTC"));
+        }
 
-        match &self.due {
-            &Some(ref due) => {
-                match &due.datetime {
-                    &Some(ref datetime) => {
-                        state.serialize_field("due_datetime", datetime)?;
-                    },
-                    &None => {
-                        match &due.date {
-                            &Some(ref date) => {
-                                state.serialize_field("due_date", date)?;
-                            },
-                            &None => {
-                                state.serialize_field("due_string", due.string())?;
-                                state.serialize_field("due_lang", "en")?;
-                            }
-                        }
-                    }
-                }
-            },
-            &None => ()
-        };
+        for datetime in &["not-a-date", "2017-12-25", "2017-12-25 12:00:00Z", "2017-12-25T12:00Z",
+                          "2017-12-25T24:00:00Z", "2017-02-30T12:00:00Z", "2017-12-25T12:00:00",
+                          "2017-12-25T12:00:00.Z", "2017-12-25T12:00:00+0000"] {
+            let error = due.set_datetime_validated(datetime).unwrap_err();
+            assert!(error.to_string().contains("RFC3339"), "{}", datetime);
+        }
 
-        state.end()
+        assert_eq!(due.datetime(), Some(String::from("2016-12-31T23:59:60Z")));
     }
-}
 
-#[cfg(test)]
-mod tests {
-    extern crate serde_json;
-    use model::task::Task;
-    use model::task::Due;
+    #[test]
+    fn serialize_task_struct_length() {
+        use self::serde_test::{assert_ser_tokens, Token};
+
+        let mut task = Task::create("Test Task");
+        assert_ser_tokens(&task, &[
+            Token::Struct { name: "Task", len: 2 },
+            Token::Str("content"), Token::Str("Test Task"),
+            Token::Str("priority"), Token::U32(1),
+            Token::StructEnd
+        ]);
+
+        let mut due = Due::create("tomorrow at noon");
+        due.set_date_validated("2017-12-25").unwrap();
+        task.set_due(Some(due));
+        assert_ser_tokens(&task, &[
+            Token::Struct { name: "Task", len: 3 },
+            Token::Str("content"), Token::Str("Test Task"),
+            Token::Str("priority"), Token::U32(1),
+            Token::Str("due_date"), Token::Str("2017-12-25"),
+            Token::StructEnd
+        ]);
+
+        let mut due = Due::create("tomorrow at noon");
+        due.set_datetime("2017-12-25T12:00:00Z");
+        task.set_due(Some(due));
+        assert_ser_tokens(&task, &[
+            Token::Struct { name: "Task", len: 3 },
+            Token::Str("content"), Token::Str("Test Task"),
+            Token::Str("priority"), Token::U32(1),
+            Token::Str("due_datetime"), Token::Str("2017-12-25T12:00:00Z"),
+            Token::StructEnd
+        ]);
+
+        let json = r#"
+            {
+                "id": 123,
+                "project_id": 456,
+                "parent_id": 789,
+                "content": "Test Task",
+                "label_ids": [1],
+                "order": 3,
+                "indent": 2,
+                "priority": 4,
+                "due": {"string": "tomorrow at noon"}
+            }
+        "#;
+
+        let task: Task = serde_json::from_str(json).unwrap();
+        assert_ser_tokens(&task, &[
+            Token::Struct { name: "Task", len: 8 },
+            Token::Str("content"), Token::Str("Test Task"),
+            Token::Str("project_id"), Token::U64(456),
+            Token::Str("parent_id"), Token::U64(789),
+            Token::Str("indent"), Token::U32(2),
+            Token::Str("label_ids"), Token::Seq { len: Some(1) }, Token::U64(1), Token::SeqEnd,
+            Token::Str("priority"), Token::U32(4),
+            Token::Str("due_string"), Token::Str("tomorrow at noon"),
+            Token::Str("due_lang"), Token::Str("en"),
+            Token::StructEnd
+        ]);
+    }
 
     #[test]
-    fn create_due() {
-        let due = Due::create("tomorrow at noon");
-        assert_eq!(due.string(), "tomorrow at noon");
+    #[cfg(feature = "chrono")]
+    fn set_due_from_chrono() {
+        use chrono::{NaiveDate, TimeZone, Utc};
+
+        let date = NaiveDate::from_ymd_opt(2016, 9, 1).unwrap();
+        let mut due = Due::create("tomorrow at 12");
+        due.set_naive_date(date);
+        assert_eq!(due.string(), "2016-09-01");
+        assert_eq!(due.as_naive_date(), Some(date));
+
+        let datetime = Utc.with_ymd_and_hms(2016, 9, 1, 9, 30, 0).unwrap();
+        due.set_utc_datetime(datetime);
+        assert_eq!(due.string(), "2016-09-01T09:30:00Z");
+        assert_eq!(due.date(), None);
+        assert_eq!(due.as_datetime(), Some(datetime));
     }
 
     #[test]
-    fn set_due_date() {
+    fn try_set_due_datetime() {
+        let mut due = Due::create("tomorrow at noon");
+        assert!(due.try_set_datetime("2017-12-25T12:00:00Z").is_ok());
+
+        let error = due.try_set_datetime("not-a-date").unwrap_err();
+        assert_eq!(error.to_string(), "invalid due date \"not-a-date\", expected an RFC3339 date \
+                                       and time (i.e. 2017-12-25T12:00:00Z)");
+        assert_eq!(due.datetime(), Some(String::from("2017-12-25T12:00:00Z")));
+    }
+
+    #[test]
+    fn due_is_overdue_and_today() {
+        use std::time::{Duration, UNIX_EPOCH};
+
+        // 2017-12-25T12:00:00Z
+        let now = UNIX_EPOCH + Duration::from_secs(1_514_203_200);
+
+        let cases = vec![
+            (r#"{"string": "someday"}"#, false, false),
+            (r#"{"string": "Dec 24", "date": "2017-12-24"}"#, true, false),
+            (r#"{"string": "Dec 25", "date": "2017-12-25"}"#, false, true),
+            (r#"{"string": "Dec 26", "date": "2017-12-26"}"#, false, false),
+            (r#"{"string": "Dec 25 11:59", "date": "2017-12-25",
+                 "datetime": "2017-12-25T11:59:59Z"}"#, true, true),
+            (r#"{"string": "Dec 25 12:01", "datetime": "2017-12-25T12:01:00Z"}"#, false, true),
+            (r#"{"string": "Dec 25 17:00", "datetime": "2017-12-25T17:00:00+05:30"}"#, true, true),
+            (r#"{"string": "Dec 26 00:30", "datetime": "2017-12-26T00:30:00+01:00"}"#, false, true),
+            (r#"{"string": "Leap day", "date": "2016-02-29"}"#, true, false)
+        ];
+
+        for (json, overdue, today) in cases {
+            let due: Due = serde_json::from_str(json).unwrap();
+            assert_eq!(due.is_overdue_at(now), overdue, "{}", json);
+            assert_eq!(due.is_today_at(now), today, "{}", json);
+        }
+    }
+
+    #[test]
+    fn try_set_due_date() {
         let mut due = Due::create("tomorrow at noon");
-        due.set_date("2017-12-25");
+        assert!(due.try_set_date("2017-12-25").is_ok());
+        assert_eq!(due.string(), "2017-12-25");
         assert_eq!(due.date(), Some(String::from("2017-12-25")));
+
+        assert_eq!(due.try_set_date("Dec 25").unwrap_err().value(), "Dec 25");
+        assert_eq!(due.try_set_date("2021-02-30").unwrap_err().value(), "2021-02-30");
         assert_eq!(due.string(), "2017-12-25");
     }
 
     #[test]
-    fn set_task_due_date() {
-        let mut due = Due::create("tomorrow at noon");
-        due.set_date("2017-12-25");
+    fn compare_tasks() {
+        let build = |content: &str| {
+            let mut task = Task::create(content);
+            task.set_priority(Priority::High);
+            task.set_due(Some(Due::create("tomorrow")));
+            task
+        };
+
+        assert_eq!(build("Test Task"), build("Test Task"));
+        assert_ne!(build("Test Task"), build("Other Task"));
+
+        let mut task = build("Test Task");
+        task.set_priority(Priority::Urgent);
+        assert_ne!(task, build("Test Task"));
+
+        let json = r#"{"content": "Test Task", "priority": 3, "due": {"string": "tomorrow"}}"#;
+        let deserialized: Task = serde_json::from_str(json).unwrap();
+        assert_eq!(deserialized, build("Test Task"));
+    }
+
+    #[test]
+    fn display_task() {
         let mut task = Task::create("Test Task");
+        task.set_priority(Priority::Medium);
+        assert_eq!(task.to_string(), "[p3] Test Task");
+
+        let mut due = Due::create("Christmas");
+        due.try_set_date("2017-12-25").unwrap();
         task.set_due(Some(due));
-        assert_eq!(task.due().unwrap().date(), Some(String::from("2017-12-25")));
+        assert_eq!(task.to_string(), "[p3] Test Task (due: 2017-12-25)");
+
+        task.set_due(Some(Due::create("every monday")));
+        assert_eq!(task.to_string(), "[p3] Test Task (due: every monday)");
+
+        let json = r#"{"content": "Done Task", "is_completed": true, "priority": 4}"#;
+        let task: Task = serde_json::from_str(json).unwrap();
+        assert_eq!(task.to_string(), "[x] [p1] Done Task");
+    }
+
+    #[test]
+    fn sort_tasks() {
+        let mut tasks = sort_fixture();
+        tasks.sort();
+        assert_eq!(contents(&tasks), ["no due, urgent", "christmas, high", "christmas eve, normal",
+                                      "christmas, normal", "christmas at noon, normal",
+                                      "no due, normal"]);
+
+        let first = Task::create("Same");
+        let mut second = Task::create("Same");
+        assert_eq!(first.cmp(&second), Ordering::Equal);
+        second.add_label_id(1);
+        assert_ne!(first.cmp(&second), Ordering::Equal);
+    }
+
+    #[test]
+    fn build_task() {
+        let task = Task::builder("Buy milk")
+            .project_id(123)
+            .parent_id(456)
+            .priority(Priority::High)
+            .due(Due::create("today"))
+            .label_id(4)
+            .label_id(4)
+            .order(2)
+            .indent(2)
+            .build()
+            .unwrap();
 
         let json = serde_json::to_string(&task).unwrap();
         println!("{}", json);
-        assert!(json.contains("\"due_date\":\"2017-12-25\""));
+        assert_eq!(json, r#"{"content":"Buy milk","project_id":123,"parent_id":456,"indent":2,"label_ids":[4],"priority":3,"due_string":"today","due_lang":"en"}"#);
+
+        assert_eq!(Task::builder("").build().unwrap_err(), TaskBuildError::EmptyContent);
+        match Task::builder("Buy milk").indent(0).build() {
+            Err(TaskBuildError::Indent(error)) => assert_eq!(error.value(), 0),
+            result => panic!("unexpected result {:?}", result)
+        }
     }
 
     #[test]
-    fn create_and_serialize_task() {
-        let new_task = Task::create("Test Task");
-        let json = serde_json::to_string(&new_task).unwrap();
+    fn set_task_project_id_and_order() {
+        let mut task = Task::create("Test Task");
+        task.set_project_id(Some(2345));
+        task.set_order(Some(3));
+        let json = serde_json::to_string(&task).unwrap();
+        println!("{}", json);
+        assert_eq!(json, r#"{"content":"Test Task","project_id":2345,"priority":1}"#);
+        assert_eq!(task.order(), &Some(3));
+
+        task.set_project_id(None);
+        task.set_order(None);
+        let json = serde_json::to_string(&task).unwrap();
+        assert_eq!(json, r#"{"content":"Test Task","priority":1}"#);
+    }
+
+    #[test]
+    fn update_payload_without_order() {
+        let json = r#"{"id": 1234, "content": "Test Task", "order": 3}"#;
+        let mut task: Task = serde_json::from_str(json).unwrap();
+        task.set_order(Some(5));
+        assert_eq!(task.update_payload().to_string(), "{}");
+        task.set_order(None);
+        assert_eq!(task.update_payload().to_string(), "{}");
+        assert!(!task.to_update_json().unwrap().contains("order"));
+    }
+
+    #[test]
+    fn deserialize_and_assign_task() {
+        let json = r#"{"id": 1234, "content": "Shared Task", "assignee": 55, "assigner": 66}"#;
+        let mut task: Task = serde_json::from_str(json).unwrap();
+        assert_eq!(task.assignee(), &Some(55));
+        assert_eq!(task.assigner(), &Some(66));
+
+        let json = serde_json::to_string(&task).unwrap();
         println!("{}", json);
-        assert!(json.contains("\"content\":\"Test Task\""));
+        assert_eq!(json, r#"{"content":"Shared Task","priority":1,"assignee":55}"#);
+        assert_eq!(task.update_payload().to_string(), "{}");
+
+        task.set_assignee(Some(77));
+        assert_eq!(task.assignee(), &Some(77));
+        assert_eq!(task.update_payload().to_string(), r#"{"assignee":77}"#);
+        assert!(task.to_update_json().unwrap().contains(r#""assignee":77"#));
+
+        task.set_assignee(None);
+        assert_eq!(task.update_payload().to_string(), r#"{"assignee":null}"#);
     }
 
     #[test]
-    fn deserialize_task() {
+    fn deserialize_task_created() {
+        let json = r#"{"id": 1234, "content": "Test Task", "created": "2019-12-11T22:36:50.000000Z"}"#;
+        let task: Task = serde_json::from_str(json).unwrap();
+        assert_eq!(task.created(), &Some(String::from("2019-12-11T22:36:50.000000Z")));
+        assert!(!serde_json::to_string(&task).unwrap().contains("created"));
+
+        let json = r#"{"id": 1234, "content": "Test Task", "created_at": "2019-12-11T22:36:50Z"}"#;
+        let task: Task = serde_json::from_str(json).unwrap();
+        assert_eq!(task.created(), &Some(String::from("2019-12-11T22:36:50Z")));
+    }
+
+    #[test]
+    fn default_task_and_due() {
+        let task = Task::default();
+        assert_eq!(task, Task::create(""));
+        assert_eq!(task.priority(), Priority::Normal);
+        assert_eq!(serde_json::to_string(&task).unwrap(),
+                   serde_json::to_string(&Task::create("")).unwrap());
+        assert_eq!(Priority::default(), Priority::Normal);
+
+        assert_eq!(Due::default(), Due::create(""));
+    }
+
+    #[test]
+    fn convert_str_to_due() {
+        assert_eq!(Due::from("tomorrow"), Due::create("tomorrow"));
+        assert_eq!(Due::from(String::from("tomorrow")), Due::create("tomorrow"));
+
+        let mut task = Task::create("Test Task");
+        task.set_due(Some("next friday".into()));
+        assert_eq!(task.due(), Some(Due::create("next friday")));
+
+        let task = Task::builder("Test Task").due("today").build().unwrap();
+        assert_eq!(task.due(), Some(Due::create("today")));
+    }
+
+    #[test]
+    fn create_task_with_due() {
+        let task = Task::create_with_due("Test Task", Due::create("every monday"));
+        assert_eq!(task.content(), "Test Task");
+        assert_eq!(task.due(), Some(Due::create("every monday")));
+
+        let json = serde_json::to_string(&task).unwrap();
+        assert!(json.contains(r#""due_string":"every monday""#));
+    }
+
+    #[test]
+    fn parse_due_and_task() {
+        let due: Due = "every monday".parse().unwrap();
+        assert_eq!(due, Due::create("every monday"));
+        let error = "".parse::<Due>().unwrap_err();
+        assert_eq!(error.value(), "");
+        assert!(error.to_string().contains("invalid due date"));
+
+        let json = r#"{"id": 1234, "content": "Buy milk", "priority": 3,
+                       "due": {"string": "tomorrow", "date": "2016-09-02"}}"#;
+        let task: Task = json.parse().unwrap();
+        assert_eq!(task.id(), &Some(1234));
+        assert_eq!(task.priority(), Priority::High);
+
+        let json = serde_json::to_string(&task).unwrap();
+        println!("{}", json);
+        assert_eq!(json, r#"{"content":"Buy milk","priority":3,"due_date":"2016-09-02"}"#);
+        let parsed: Task = json.parse().unwrap();
+        assert_eq!(parsed.content(), "Buy milk");
+        assert_eq!(parsed.priority(), Priority::High);
+
+        assert!("{\"id\": 1234}".parse::<Task>().is_err());
+    }
+
+    #[test]
+    fn deserialize_64_bit_ids() {
         let json = r#"
             {
-                "comment_count": 10,
-                "completed": true,
-                "content": "My task",
-                "due": {
-                    "date": "2016-09-01",
-                    "recurring": true,
-                    "datetime": "2016-09-01T09:00:00Z",
-                    "string": "tomorrow at 12",
-                    "timezone": "Europe/Moscow"
-                },
-                "id": 1234,
-                "indent": 1,
-                "label_ids": [
-                    124,
-                    125,
-                    128
-                ],
-                "order": 123,
-                "priority": 1,
-                "project_id": 2345,
-                "url": "https://todoist.com/showTask?id=12345&sync_id=56789"
+                "id": 6791728361,
+                "project_id": 2203306141,
+                "section_id": 7025000001,
+                "parent_id": 6791728360,
+                "content": "Test Task",
+                "label_ids": [2156154810]
             }
         "#;
 
         let task: Task = serde_json::from_str(json).unwrap();
-        println!("{:?}", task);
-        println!("{}", serde_json::to_string(&task).unwrap());
+        assert_eq!(task.id(), &Some(6791728361));
+        assert_eq!(task.section_id(), &Some(7025000001));
+        assert_eq!(task.parent_id(), &Some(6791728360));
+        assert!(task.has_label(2156154810));
+
+        let json = serde_json::to_string(&task).unwrap();
+        assert!(json.contains(r#""project_id":2203306141"#));
+        assert!(json.contains(r#""parent_id":6791728360"#));
+    }
+
+    /// Creates the tasks shared by the filter and `sort_tasks_by` tests, in the order of their
+    /// identifiers.
+    fn fixture_tasks() -> Vec<Task> {
+        let json = r#"
+            [
+                {"id": 1, "project_id": 10, "content": "Buy milk", "label_ids": [100],
+                 "priority": 4, "order": 4, "due": {"string": "christmas", "date": "2017-12-25"}},
+                {"id": 2, "project_id": 10, "content": "Buy bread", "completed": true, "order": 7,
+                 "due": {"string": "new year", "date": "2018-01-01",
+                         "datetime": "2018-01-01T09:00:00Z"}},
+                {"id": 3, "project_id": 20, "content": "Call MOM", "label_ids": [100, 200],
+                 "priority": 2, "order": 6},
+                {"id": 4, "project_id": 20, "content": "Pay rent", "priority": 4, "order": 3,
+                 "due": {"string": "first", "date": "2017-12-01"}},
+                {"id": 5, "project_id": 30, "content": "Wrap presents", "order": 1,
+                 "due": {"string": "christmas at noon", "date": "2017-12-25",
+                         "datetime": "2017-12-25T12:00:00Z"}},
+                {"id": 6, "project_id": 30, "content": "Answer email"},
+                {"id": 7, "project_id": 30, "content": "Bake cookies", "order": 2,
+                 "due": {"string": "christmas", "date": "2017-12-25"}}
+            ]
+        "#;
+        serde_json::from_str(json).unwrap()
+    }
+
+    /// Gets the identifiers of the tasks.
+    fn ids(tasks: &[Task]) -> Vec<u64> {
+        tasks.iter().map(|task| task.id().unwrap()).collect()
+    }
+
+    /// Gets the identifiers of the tasks matching the filter.
+    fn matching_ids(filter: &Filter) -> Vec<u64> {
+        let tasks: Vec<Task> = fixture_tasks().into_iter()
+            .filter(|task| task.matches_filter(filter))
+            .collect();
+        ids(&tasks)
     }
 
     #[test]
-    fn update_task_properties() {
-        let mut task = Task::create("Test Task");
-        task.set_content("New Task Name");
-        task.set_priority(3);
-        task.set_completed(true);
-        task.add_label_id(10);
-        task.add_label_id(4);
-        task.add_label_id(1);
-        assert_eq!(task.label_ids(), [10, 4, 1]);
-        task.remove_label_id(4);
-        assert_eq!(task.label_ids(), [10, 1]);
+    fn match_empty_filter() {
+        assert_eq!(matching_ids(&Filter::new()), vec![1, 2, 3, 4, 5, 6, 7]);
+    }
 
-        println!("{:?}", task);
+    #[test]
+    fn match_filter_by_project_label_and_priority() {
+        let mut filter = Filter::new();
+        filter.set_project_id(Some(20));
+        assert_eq!(matching_ids(&filter), vec![3, 4]);
 
-        let json = serde_json::to_string(&task).unwrap();
-        println!("{}", json);
-        assert!(json.contains("New Task Name"));
+        let mut filter = Filter::new();
+        filter.set_label_id(Some(100));
+        assert_eq!(matching_ids(&filter), vec![1, 3]);
+
+        let mut filter = Filter::new();
+        filter.set_priority(Some(Priority::Urgent));
+        assert_eq!(matching_ids(&filter), vec![1, 4]);
+    }
+
+    #[test]
+    fn match_filter_by_completion_and_content() {
+        let mut filter = Filter::new();
+        filter.set_completed(Some(true));
+        assert_eq!(matching_ids(&filter), vec![2]);
+        filter.set_completed(Some(false));
+        assert_eq!(matching_ids(&filter), vec![1, 3, 4, 5, 6, 7]);
+
+        let mut filter = Filter::new();
+        filter.set_content_contains(Some("buy"));
+        assert_eq!(matching_ids(&filter), vec![1, 2]);
+        filter.set_content_contains(Some("mom"));
+        assert_eq!(matching_ids(&filter), vec![3]);
+    }
+
+    #[test]
+    fn match_filter_by_due_date() {
+        let mut filter = Filter::new();
+        filter.set_due_before(Some("2017-12-25")).unwrap();
+        assert_eq!(matching_ids(&filter), vec![4]);
+
+        let mut filter = Filter::new();
+        filter.set_due_after(Some("2017-12-01")).unwrap();
+        assert_eq!(matching_ids(&filter), vec![1, 2, 5, 7]);
+        filter.set_due_before(Some("2018-01-01")).unwrap();
+        assert_eq!(matching_ids(&filter), vec![1, 5, 7]);
+
+        assert_eq!(filter.set_due_after(Some("2017-13-01")).unwrap_err().value(), "2017-13-01");
+        assert_eq!(filter.due_after(), &Some(String::from("2017-12-01")));
+        filter.set_due_after(None).unwrap();
+        assert_eq!(matching_ids(&filter), vec![1, 4, 5, 7]);
+    }
+
+    #[test]
+    fn match_filter_with_several_criteria() {
+        let mut filter = Filter::new();
+        filter.set_priority(Some(Priority::Urgent));
+        filter.set_label_id(Some(100));
+        assert_eq!(matching_ids(&filter), vec![1]);
+
+        filter.set_project_id(Some(20));
+        assert!(matching_ids(&filter).is_empty());
+
+        let mut filter = Filter::new();
+        filter.set_project_id(Some(10));
+        filter.set_completed(Some(false));
+        filter.set_content_contains(Some("Buy"));
+        filter.set_due_before(Some("2018-01-01")).unwrap();
+        assert_eq!(matching_ids(&filter), vec![1]);
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn task_is_overdue() {
+        use chrono::{TimeZone, Utc};
+
+        let now = Utc.with_ymd_and_hms(2017, 12, 25, 12, 0, 0).unwrap();
+        let mut past = Due::create("yesterday");
+        past.set_date_validated("2017-12-24").unwrap();
+        let mut future = Due::create("tomorrow at noon");
+        future.set_datetime("2017-12-26T12:00:00Z");
+
+        let overdue = Task::create_with_due("Overdue", past.clone());
+        assert!(overdue.is_overdue(now));
+
+        let upcoming = Task::create_with_due("Upcoming", future);
+        assert!(!upcoming.is_overdue(now));
+
+        let mut completed = Task::create_with_due("Completed", past);
+        completed.set_completed(true);
+        assert!(!completed.is_overdue(now));
+
+        assert!(!Task::create("No due date").is_overdue(now));
+    }
+
+    /// Gets the identifiers of the tasks sorted by the key and direction.
+    fn sorted_ids(key: TaskSortKey, direction: SortDirection) -> Vec<u64> {
+        let mut tasks = fixture_tasks();
+        sort_tasks_by(&mut tasks, key, direction);
+        ids(&tasks)
+    }
+
+    #[test]
+    fn sort_tasks_by_order_key() {
+        assert_eq!(sorted_ids(TaskSortKey::Order, SortDirection::Ascending),
+                   vec![5, 7, 4, 1, 3, 2, 6]);
+        assert_eq!(sorted_ids(TaskSortKey::Order, SortDirection::Descending),
+                   vec![6, 2, 3, 1, 4, 7, 5]);
+    }
+
+    #[test]
+    fn sort_tasks_by_priority_key() {
+        assert_eq!(sorted_ids(TaskSortKey::Priority, SortDirection::Ascending),
+                   vec![2, 5, 6, 7, 3, 1, 4]);
+        assert_eq!(sorted_ids(TaskSortKey::Priority, SortDirection::Descending),
+                   vec![1, 4, 3, 2, 5, 6, 7]);
+    }
+
+    #[test]
+    fn sort_tasks_by_due_date_key() {
+        assert_eq!(sorted_ids(TaskSortKey::DueDate, SortDirection::Ascending),
+                   vec![4, 1, 7, 5, 2, 3, 6]);
+        assert_eq!(sorted_ids(TaskSortKey::DueDate, SortDirection::Descending),
+                   vec![3, 6, 2, 5, 1, 7, 4]);
+    }
+
+    #[test]
+    fn sort_tasks_by_content_key() {
+        assert_eq!(sorted_ids(TaskSortKey::Content, SortDirection::Ascending),
+                   vec![6, 7, 2, 1, 3, 4, 5]);
+        assert_eq!(sorted_ids(TaskSortKey::Content, SortDirection::Descending),
+                   vec![5, 4, 3, 1, 2, 7, 6]);
     }
 }
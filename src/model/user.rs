@@ -0,0 +1,191 @@
+//! # User
+//!
+//! Module containing user-related structures and utilities.
+
+use model::UserId;
+use model::id;
+
+/// Data model for a Todoist user.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct User {
+    /// User identifier
+    #[serde(with = "id")]
+    id: UserId,
+    /// Full name of the user
+    name: String,
+    /// Email address of the user
+    email: String
+}
+
+impl User {
+    /// Gets the user identifier.
+    pub fn id(&self) -> UserId {
+        self.id
+    }
+
+    /// Gets the full name of the user.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the email address of the user.
+    pub fn email(&self) -> &str {
+        &self.email
+    }
+}
+
+/// The state of a collaborator in a shared project.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum CollaboratorState {
+    /// The collaborator joined the project
+    Active,
+    /// The collaborator was invited but did not join the project yet
+    Invited,
+    /// The collaborator was removed from the project
+    Deleted
+}
+
+impl Default for CollaboratorState {
+    /// Gets the state of collaborators listed without one, which is `CollaboratorState::Active`.
+    fn default() -> CollaboratorState {
+        CollaboratorState::Active
+    }
+}
+
+/// Data model for a user collaborating on a shared project.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Collaborator {
+    /// User identifier of the collaborator
+    #[serde(with = "id")]
+    id: UserId,
+    /// Full name of the collaborator
+    name: String,
+    /// Email address of the collaborator
+    email: String,
+    /// State of the collaborator in the project
+    #[serde(default)]
+    state: CollaboratorState
+}
+
+impl Collaborator {
+    /// Gets the user identifier of the collaborator.
+    pub fn id(&self) -> UserId {
+        self.id
+    }
+
+    /// Gets the full name of the collaborator.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the email address of the collaborator.
+    pub fn email(&self) -> &str {
+        &self.email
+    }
+
+    /// Gets the state of the collaborator in the project.
+    pub fn state(&self) -> CollaboratorState {
+        self.state
+    }
+}
+
+/// Finds the collaborator with the given user identifier, i.e. the assignee of a task.
+pub fn find_by_id(collaborators: &[Collaborator], id: UserId) -> Option<&Collaborator> {
+    collaborators.iter().find(|collaborator| collaborator.id == id)
+}
+
+/// Finds the collaborator with the given email address, ignoring case.
+///
+/// Useful to get the user identifier to assign a task to.
+pub fn find_by_email<'a>(collaborators: &'a [Collaborator], email: &str)
+        -> Option<&'a Collaborator> {
+    collaborators.iter().find(|collaborator| collaborator.email.eq_ignore_ascii_case(email))
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate serde_json;
+    use model::user::{User, Collaborator, CollaboratorState, find_by_email, find_by_id};
+    use model::task::Task;
+
+    #[test]
+    fn deserialize_user() {
+        let json = r#"
+            {
+                "id": 2671355,
+                "name": "Alice",
+                "email": "alice@example.com"
+            }
+        "#;
+
+        let user: User = serde_json::from_str(json).unwrap();
+        assert_eq!(user.id(), 2671355);
+        assert_eq!(user.name(), "Alice");
+        assert_eq!(user.email(), "alice@example.com");
+    }
+
+    #[test]
+    fn deserialize_collaborators() {
+        let json = r#"
+            [
+                {
+                    "id": 2671355,
+                    "name": "Alice",
+                    "email": "alice@example.com"
+                },
+                {
+                    "id": 2671366,
+                    "name": "Bob",
+                    "email": "bob@example.com",
+                    "state": "invited"
+                }
+            ]
+        "#;
+
+        let collaborators: Vec<Collaborator> = serde_json::from_str(json).unwrap();
+        assert_eq!(collaborators[0].id(), 2671355);
+        assert_eq!(collaborators[0].state(), CollaboratorState::Active);
+        assert_eq!(collaborators[1].email(), "bob@example.com");
+        assert_eq!(collaborators[1].state(), CollaboratorState::Invited);
+
+        let json = serde_json::to_string(&collaborators[1]).unwrap();
+        assert_eq!(json, r#"{"id":2671366,"name":"Bob","email":"bob@example.com","state":"invited"}"#);
+    }
+
+    #[test]
+    fn find_collaborator_by_email() {
+        let json = r#"
+            [
+                {"id": 2671355, "name": "Alice", "email": "alice@example.com"},
+                {"id": 2671366, "name": "Bob", "email": "Bob@Example.com"}
+            ]
+        "#;
+
+        let collaborators: Vec<Collaborator> = serde_json::from_str(json).unwrap();
+        assert_eq!(find_by_email(&collaborators, "alice@example.com").unwrap().id(), 2671355);
+        assert_eq!(find_by_email(&collaborators, "bob@example.com").unwrap().name(), "Bob");
+        assert!(find_by_email(&collaborators, "carol@example.com").is_none());
+        assert!(find_by_email(&[], "alice@example.com").is_none());
+
+        let fetched: Vec<Collaborator> = serde_json::from_str(json).unwrap();
+        assert_eq!(fetched, collaborators);
+    }
+
+    #[test]
+    fn find_assignee_by_id() {
+        let json = r#"
+            [
+                {"id": 2671355, "name": "Alice", "email": "alice@example.com"},
+                {"id": 2671366, "name": "Bob", "email": "bob@example.com"}
+            ]
+        "#;
+        let collaborators: Vec<Collaborator> = serde_json::from_str(json).unwrap();
+
+        let task: Task = serde_json::from_str(r#"{"id": 1, "content": "Buy milk",
+                                                  "assignee_id": 2671366}"#).unwrap();
+        let assignee = task.assignee().and_then(|id| find_by_id(&collaborators, id));
+        assert_eq!(assignee.unwrap().name(), "Bob");
+        assert!(find_by_id(&collaborators, 1).is_none());
+    }
+}
@@ -0,0 +1,147 @@
+//! # Parse
+//!
+//! Module containing lenient parsers for bulk API responses.
+//!
+//! Deserializing a JSON array into a `Vec` fails as a whole when a single element is malformed.
+//! The lossy parsers deserialize each element on its own instead, so one bad element does not
+//! lose the others.
+
+use std::error::Error;
+use std::fmt;
+use serde::de::DeserializeOwned;
+use serde_json::{self, Value};
+use model::task::Task;
+use model::project::Project;
+use model::comment::Comment;
+
+/// Failure to parse a single element of a JSON array.
+#[derive(Debug)]
+pub struct ParseFailure {
+    /// Position of the element in the array
+    index: usize,
+    /// Reason the element could not be parsed
+    error: serde_json::Error
+}
+
+impl ParseFailure {
+    /// Gets the position of the element in the array.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Gets the reason the element could not be parsed.
+    pub fn error(&self) -> &serde_json::Error {
+        &self.error
+    }
+}
+
+impl fmt::Display for ParseFailure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "element {}: {}", self.index, self.error)
+    }
+}
+
+impl Error for ParseFailure {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Parses a JSON array of tasks, skipping the elements that are not valid tasks.
+///
+/// # Example
+///
+/// ```
+/// use todoist_rest::parse;
+///
+/// let json = r#"[{"content": "My task"}, {"content": 7}]"#;
+/// let (tasks, failures) = parse::tasks_lossy(json).unwrap();
+/// assert_eq!(tasks.len(), 1);
+/// assert_eq!(failures[0].index(), 1);
+/// ```
+///
+/// # Errors
+///
+/// Returns a `serde_json::Error` if the JSON is not an array.
+pub fn tasks_lossy(json: &str) -> Result<(Vec<Task>, Vec<ParseFailure>), serde_json::Error> {
+    lossy(json)
+}
+
+/// Parses a JSON array of projects, skipping the elements that are not valid projects.
+///
+/// # Errors
+///
+/// Returns a `serde_json::Error` if the JSON is not an array.
+pub fn projects_lossy(json: &str)
+        -> Result<(Vec<Project>, Vec<ParseFailure>), serde_json::Error> {
+    lossy(json)
+}
+
+/// Parses a JSON array of comments, skipping the elements that are not valid comments.
+///
+/// # Errors
+///
+/// Returns a `serde_json::Error` if the JSON is not an array.
+pub fn comments_lossy(json: &str)
+        -> Result<(Vec<Comment>, Vec<ParseFailure>), serde_json::Error> {
+    lossy(json)
+}
+
+/// Parses a JSON array element by element, collecting the elements that fail to parse.
+fn lossy<T: DeserializeOwned>(json: &str)
+        -> Result<(Vec<T>, Vec<ParseFailure>), serde_json::Error> {
+    let values: Vec<Value> = serde_json::from_str(json)?;
+    let mut items = vec![];
+    let mut failures = vec![];
+
+    for (index, value) in values.into_iter().enumerate() {
+        match serde_json::from_value(value) {
+            Ok(item) => items.push(item),
+            Err(error) => failures.push(ParseFailure { index, error })
+        }
+    }
+
+    Ok((items, failures))
+}
+
+#[cfg(test)]
+mod tests {
+    use parse;
+
+    #[test]
+    fn parse_tasks_lossy() {
+        let mut elements = vec![];
+
+        for index in 0..10 {
+            elements.push(match index {
+                3 => String::from(r#"{"id": 3, "content": "Task 3", "due": {"date": "2017-12-25"}}"#),
+                7 => String::from(r#"{"id": 7, "content": "Task 7", "priority": 9}"#),
+                _ => format!(r#"{{"id": {0}, "content": "Task {0}"}}"#, index)
+            });
+        }
+
+        let json = format!("[{}]", elements.join(","));
+        let (tasks, failures) = parse::tasks_lossy(&json).unwrap();
+        assert_eq!(tasks.len(), 8);
+        assert_eq!(tasks[3].content(), "Task 4");
+        assert_eq!(failures.len(), 2);
+        assert_eq!(failures[0].index(), 3);
+        assert_eq!(failures[1].index(), 7);
+        assert!(failures[1].to_string().contains("invalid priority 9"));
+    }
+
+    #[test]
+    fn parse_projects_and_comments_lossy() {
+        let json = r#"[{"id": 1, "name": "Inbox"}, {"id": 2}]"#;
+        let (projects, failures) = parse::projects_lossy(json).unwrap();
+        assert_eq!(projects.len(), 1);
+        assert_eq!(failures[0].index(), 1);
+
+        let json = r#"[{"id": 1}, {"id": 2, "task_id": 5, "content": "Note"}]"#;
+        let (comments, failures) = parse::comments_lossy(json).unwrap();
+        assert_eq!(comments[0].content(), "Note");
+        assert_eq!(failures[0].index(), 0);
+
+        assert!(parse::tasks_lossy(r#"{"content": "My task"}"#).is_err());
+    }
+}